@@ -7,11 +7,8 @@ use gpt5::{Gpt5Client, Gpt5Model};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize the client with your API key
-    let api_key =
-        std::env::var("OPENAI_API_KEY").expect("Please set OPENAI_API_KEY environment variable");
-
-    let client = Gpt5Client::new(api_key);
+    // Initialize the client from OPENAI_API_KEY (and optional OPENAI_* settings)
+    let client = Gpt5Client::from_env()?;
 
     // Simple text generation
     println!("🤖 Asking GPT-5 Nano a simple question...");
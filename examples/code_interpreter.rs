@@ -0,0 +1,48 @@
+//! Code interpreter example for the GPT-5 Rust client
+//!
+//! Asks GPT-5 to chart a CSV with the hosted code interpreter and saves the
+//! generated files locally.
+//! Run with: cargo run --example code_interpreter -- <uploaded-csv-file-id>
+
+use gpt5::{CodeInterpreterOutput, Gpt5Client, Gpt5Model, Gpt5RequestBuilder};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Gpt5Client::from_env()?;
+
+    // The CSV must already be uploaded with the Files API (purpose "user_data")
+    let Some(file_id) = std::env::args().nth(1) else {
+        eprintln!("Usage: cargo run --example code_interpreter -- <file-id>");
+        return Ok(());
+    };
+
+    println!("📊 Asking GPT-5 to chart {}...\n", file_id);
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input(
+            "Plot the main numeric column of the attached CSV over time and save it as chart.png",
+        )
+        .code_interpreter_files([file_id])
+        .code_interpreter_outputs(true)
+        .build();
+    let response = client.request(request).await?;
+
+    for call in response.code_interpreter_calls() {
+        println!("Ran:\n{}\n", call.code.as_deref().unwrap_or_default());
+        for output in call.outputs.iter().flatten() {
+            if let CodeInterpreterOutput::Logs { logs } = output {
+                println!("Logs:\n{}", logs);
+            }
+        }
+    }
+
+    if let Some(text) = response.text() {
+        println!("Response:\n{}\n", text);
+    }
+
+    for path in client.download_container_files(&response, "charts").await? {
+        println!("Saved {}", path.display());
+    }
+
+    Ok(())
+}
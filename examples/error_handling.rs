@@ -30,9 +30,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Test with valid API key (if available)
-    if let Ok(api_key) = std::env::var("OPENAI_API_KEY") {
+    if let Ok(client) = Gpt5Client::from_env() {
         println!("\n✅ Testing with valid API key...");
-        let client = Gpt5Client::new(api_key);
 
         // Test empty input
         println!("📝 Testing empty input...");
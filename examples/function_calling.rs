@@ -8,10 +8,7 @@ use serde_json::json;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let api_key =
-        std::env::var("OPENAI_API_KEY").expect("Please set OPENAI_API_KEY environment variable");
-
-    let client = Gpt5Client::new(api_key);
+    let client = Gpt5Client::from_env()?;
 
     // Define a simple calculator tool
     let calculator_tool = Tool {
@@ -28,6 +25,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             },
             "required": ["expression"]
         })),
+        ..Default::default()
     };
 
     // Define a weather tool (mock)
@@ -45,6 +43,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             },
             "required": ["city"]
         })),
+        ..Default::default()
     };
 
     println!("🧮 Testing calculator function...");
@@ -0,0 +1,49 @@
+//! Mock server example - run the client without an API key
+//!
+//! Starts the bundled mock Responses API locally and sends a few requests to it
+//! Run with: cargo run --example mock_server --features mock
+
+use gpt5::mock::{MockReply, MockScript, MockServer};
+use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder};
+use serde_json::json;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Script the replies the "API" will send back, in order
+    let script = MockScript::new(vec![
+        MockReply::text("Paris is the capital of France."),
+        MockReply::function_call("get_weather", json!({ "location": "Boston" })),
+        MockReply::error(429),
+    ]);
+    let server = MockServer::start(script).await?;
+    println!("🧪 Mock server running at {}", server.base_url());
+
+    let client = Gpt5Client::new("mock-key".to_string()).with_base_url(server.base_url());
+
+    // 1. Plain text reply
+    let answer = client
+        .simple(Gpt5Model::Gpt5Nano, "What is the capital of France?")
+        .await?;
+    println!("💬 {}", answer);
+
+    // 2. Function call reply
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("What's the weather in Boston?")
+        .build();
+    let response = client.request(request).await?;
+    for call in response.function_calls() {
+        println!(
+            "🔧 Function call: {:?} with {:?}",
+            call.name, call.arguments
+        );
+    }
+
+    // 3. Rate limit error
+    match client.simple(Gpt5Model::Gpt5Nano, "One more?").await {
+        Ok(text) => println!("Unexpected success: {}", text),
+        Err(error) => println!("❌ Expected error: {}", error),
+    }
+
+    println!("📨 The mock received {} requests", server.requests().len());
+    Ok(())
+}
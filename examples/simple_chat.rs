@@ -8,10 +8,7 @@ use std::io::{self, Write};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let api_key =
-        std::env::var("OPENAI_API_KEY").expect("Please set OPENAI_API_KEY environment variable");
-
-    let client = Gpt5Client::new(api_key);
+    let client = Gpt5Client::from_env()?;
 
     println!("🤖 GPT-5 Chat Bot");
     println!("Type 'quit' or 'exit' to end the conversation\n");
@@ -4,15 +4,11 @@
 //! optionally override the query or result limits.
 //! Run with: cargo run --example web_search
 
-use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder, OutputType, Status};
+use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder, Status};
 use std::time::Duration;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Pull the API key from the environment
-    let api_key =
-        std::env::var("OPENAI_API_KEY").expect("Please set OPENAI_API_KEY environment variable");
-
     // Configure a reqwest client with a shorter timeout to fail fast in demos.
     // You can customise this however you like (proxies, retries, etc.).
     let http_client = reqwest::Client::builder()
@@ -21,7 +17,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .expect("failed to build reqwest client");
 
     // Build the GPT-5 client with the custom HTTP client for extra control.
-    let client = Gpt5Client::new(api_key).with_http_client(http_client);
+    let client = Gpt5Client::from_env()?.with_http_client(http_client);
 
     println!("🔎 Asking GPT-5 to perform a live web-assisted search...\n");
 
@@ -30,6 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .web_search_enabled(true)
         .web_search_query("latest Rust release notes")
         .web_search_max_results(3)
+        .web_search_sources(true)
         .build();
 
     let search_config = request.web_search_config.clone();
@@ -58,20 +55,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    if let Some(outputs) = &response.output {
-        for output in outputs {
-            if let OutputType::Unknown(ref kind) = output.output_type {
-                if kind == "tool_call" {
-                    println!(
-                        "Tool call requested: {} with args {}",
-                        output.name.as_deref().unwrap_or("web_search"),
-                        output.arguments.as_deref().unwrap_or("{}")
-                    );
-                }
+    for call in response.web_search_calls() {
+        if let Some(action) = &call.action {
+            println!("Web search: {:?}", action.query().unwrap_or("(no query)"));
+            for source in action.sources() {
+                println!("  consulted {}", source.url.as_deref().unwrap_or("?"));
             }
         }
     }
 
+    for citation in response.citations() {
+        println!(
+            "Cited: {} <{}>",
+            citation.title.as_deref().unwrap_or("untitled"),
+            citation.url
+        );
+    }
+
     if response.output.is_none() {
         println!(
             "No output yet; inspect the full payload for details: {:#?}",
@@ -0,0 +1,153 @@
+//! Authentication and URL strategies
+//!
+//! `Gpt5Client` talks to OpenAI by default: requests go to
+//! `{base_url}/v1/responses` with an `Authorization: Bearer` header. Azure
+//! OpenAI differs in both respects, so the client combines an [`Auth`]
+//! strategy (how to authenticate) with an [`Endpoint`] (how to build URLs).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::{AzureConfig, Gpt5Client};
+//!
+//! // Azure OpenAI with an `api-key` header
+//! let client = Gpt5Client::azure(
+//!     "https://my-resource.openai.azure.com".to_string(),
+//!     AzureConfig::deployment("gpt-5-prod", "2025-04-01-preview"),
+//!     "azure-key".to_string(),
+//! );
+//! ```
+
+use crate::client::encode_component;
+use crate::middleware::BoxFuture;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Source of bearer tokens, such as Microsoft Entra ID credentials
+///
+/// The provider is asked for a token before every request, so
+/// implementations should cache tokens until shortly before they expire.
+/// Any `Fn() -> impl Future<Output = anyhow::Result<String>>` closure is a
+/// `TokenProvider`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::{Auth, Gpt5Client};
+/// use std::sync::Arc;
+///
+/// let provider = || async { Ok::<_, anyhow::Error>("token-from-entra".to_string()) };
+/// let client = Gpt5Client::new(String::new()).with_auth(Auth::TokenProvider(Arc::new(provider)));
+/// ```
+pub trait TokenProvider: Send + Sync {
+    /// Return a currently valid access token
+    fn token(&self) -> BoxFuture<'_, anyhow::Result<String>>;
+}
+
+impl<F, Fut> TokenProvider for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = anyhow::Result<String>> + Send + 'static,
+{
+    fn token(&self) -> BoxFuture<'_, anyhow::Result<String>> {
+        Box::pin(self())
+    }
+}
+
+/// How each request is authenticated
+#[derive(Clone, Default)]
+pub enum Auth {
+    /// `Authorization: Bearer <api_key>` using `Gpt5Client::api_key` (OpenAI)
+    #[default]
+    Bearer,
+    /// `api-key: <api_key>` using `Gpt5Client::api_key` (Azure OpenAI key auth)
+    AzureApiKey,
+    /// `Authorization: Bearer <token>` with a token fetched per request (Azure Entra ID)
+    TokenProvider(Arc<dyn TokenProvider>),
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::Bearer => f.write_str("Bearer"),
+            Auth::AzureApiKey => f.write_str("AzureApiKey"),
+            Auth::TokenProvider(_) => f.write_str("TokenProvider(..)"),
+        }
+    }
+}
+
+/// How request URLs are built from `Gpt5Client::base_url`
+#[derive(Debug, Clone, Default)]
+pub enum Endpoint {
+    /// OpenAI-style URLs: `{base_url}/v1/{path}`
+    #[default]
+    OpenAi,
+    /// Azure OpenAI URLs with an `api-version` query parameter
+    Azure(AzureConfig),
+}
+
+/// Azure OpenAI routing options
+#[derive(Debug, Clone)]
+pub struct AzureConfig {
+    /// Deployment that serves `/responses` calls; `None` uses the resource-level route
+    pub deployment: Option<String>,
+    /// Value of the `api-version` query parameter
+    pub api_version: String,
+}
+
+impl AzureConfig {
+    /// Route responses through `/openai/deployments/{deployment}/responses`
+    pub fn deployment(deployment: &str, api_version: &str) -> Self {
+        Self {
+            deployment: Some(deployment.to_string()),
+            api_version: api_version.to_string(),
+        }
+    }
+
+    /// Route responses through the resource-level `/openai/responses` path
+    ///
+    /// The `model` field of each request then names the deployment.
+    pub fn resource(api_version: &str) -> Self {
+        Self {
+            deployment: None,
+            api_version: api_version.to_string(),
+        }
+    }
+}
+
+impl Endpoint {
+    /// Build the URL for an API path such as `responses` or `models`
+    ///
+    /// For Azure, only the `responses` routes are deployment scoped; other
+    /// paths use the resource-level `/openai/{path}` form. The deployment
+    /// name and `api-version` are percent-encoded.
+    pub fn url(&self, base_url: &str, path: &str) -> String {
+        let base_url = base_url.trim_end_matches('/');
+        let path = path.trim_start_matches('/');
+
+        match self {
+            Endpoint::OpenAi => format!("{}/v1/{}", base_url, path),
+            Endpoint::Azure(config) => {
+                let route = match &config.deployment {
+                    Some(deployment) if path.starts_with("responses") => {
+                        format!(
+                            "openai/deployments/{}/{}",
+                            encode_component(deployment),
+                            path
+                        )
+                    }
+                    _ => format!("openai/{}", path),
+                };
+                let separator = if route.contains('?') { '&' } else { '?' };
+                format!(
+                    "{}/{}{}api-version={}",
+                    base_url,
+                    route,
+                    separator,
+                    encode_component(&config.api_version)
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Standalone mock of the OpenAI Responses API
+//!
+//! Usage: gpt5-mock [--script replies.json] [--addr 127.0.0.1:8080]
+//!
+//! Point `Gpt5Client::with_base_url` at `http://<addr>` to use it.
+
+use gpt5::mock::{MockScript, MockServer};
+use std::net::SocketAddr;
+
+const USAGE: &str = "Usage: gpt5-mock [--script <file.json>] [--addr <host:port>]";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let mut script = MockScript::default();
+    let mut addr: SocketAddr = "127.0.0.1:8080".parse()?;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--script" | "-s" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--script requires a path\n{}", USAGE))?;
+                script = MockScript::from_path(path)?;
+            }
+            "--addr" | "-a" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--addr requires a value\n{}", USAGE))?;
+                addr = value.parse()?;
+            }
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            other => return Err(anyhow::anyhow!("Unknown argument: {}\n{}", other, USAGE)),
+        }
+    }
+
+    let replies = script.replies.len();
+    let server = MockServer::bind(addr, script).await?;
+    println!(
+        "gpt5-mock listening on {} ({} scripted replies)",
+        server.base_url(),
+        replies
+    );
+
+    server.wait().await;
+    Ok(())
+}
@@ -0,0 +1,411 @@
+//! Bulk requests from a JSONL file (`gpt5 batch`)
+//!
+//! Each input line is one of:
+//!
+//! ```text
+//! "Translate 'hello' to French"
+//! {"id": "q1", "prompt": "Translate 'hello' to French"}
+//! {"id": "q2", "prompt": "...", "model": "gpt-5-mini", "reasoning": {"effort": "low"}}
+//! {"id": "q3", "model": "gpt-5-mini", "input": "...", "reasoning": {"effort": "low"}}
+//! ```
+//!
+//! Prompts are built with the command-line request flags. Next to a
+//! `prompt`, `model`, `instructions`, `reasoning` and `max_output_tokens`
+//! override those flags and any other key is an error. Objects without a
+//! `prompt` are sent as complete `Gpt5Request`s. Lines without an `id` are
+//! identified by their line number (`line-<n>`).
+//!
+//! Results are appended to the output file as they finish:
+//!
+//! ```text
+//! {"id": "q1", "text": "Bonjour", "response": {...}}
+//! {"id": "q2", "error": "OpenAI API error (status 429): ..."}
+//! {"id": "q4", "error": "GPT-5 response incomplete: max_output_tokens", "response": {...}}
+//! ```
+//!
+//! Responses that report an error or end with any status but `completed`
+//! count as failures. Ids that already have a successful result in the
+//! output file are skipped, so an interrupted run can be restarted with the
+//! same command. Failed ids are retried.
+
+use crate::usage::UsageTotals;
+use crate::RequestArgs;
+use anyhow::Context;
+use clap::Args;
+use gpt5::{Gpt5Client, Gpt5Request, Gpt5Response};
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinSet;
+
+/// Arguments for `gpt5 batch`
+#[derive(Debug, Args)]
+pub struct BatchArgs {
+    /// JSONL file of prompts or requests
+    input: PathBuf,
+
+    /// JSONL file results are appended to
+    #[arg(short, long, value_name = "FILE")]
+    output: PathBuf,
+
+    /// Maximum number of requests in flight
+    #[arg(short = 'c', long, default_value_t = 4)]
+    concurrency: usize,
+
+    /// Maximum number of requests started per minute
+    #[arg(long, value_name = "N")]
+    rpm: Option<u32>,
+
+    #[command(flatten)]
+    request: RequestArgs,
+}
+
+/// One parsed input line
+struct BatchItem {
+    id: String,
+    request: Gpt5Request,
+}
+
+/// Run every pending item and append results to the output file
+pub async fn run(args: BatchArgs) -> anyhow::Result<()> {
+    if args.concurrency == 0 {
+        anyhow::bail!("--concurrency must be at least 1");
+    }
+    if args.rpm == Some(0) {
+        anyhow::bail!("--rpm must be at least 1");
+    }
+
+    let items = read_items(&args.input, &args.request)?;
+    let completed = completed_ids(&args.output)?;
+    let total = items.len();
+    let pending: Vec<BatchItem> = items
+        .into_iter()
+        .filter(|item| !completed.contains(&item.id))
+        .collect();
+    let skipped = total - pending.len();
+    eprintln!(
+        "batch: {} requests, {} already completed, {} to run",
+        total,
+        skipped,
+        pending.len()
+    );
+
+    let client = Arc::new(Gpt5Client::from_env()?);
+    let semaphore = Arc::new(Semaphore::new(args.concurrency));
+    let mut interval = args.rpm.map(|rpm| {
+        let mut interval = tokio::time::interval(Duration::from_secs(60) / rpm);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        interval
+    });
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let writer = tokio::spawn(write_results(args.output.clone(), receiver, pending.len()));
+    let mut tasks = JoinSet::new();
+
+    for item in pending {
+        if let Some(interval) = &mut interval {
+            interval.tick().await;
+        }
+        let permit = Arc::clone(&semaphore).acquire_owned().await?;
+        // The writer has stopped, e.g. on a write error; its result says why
+        if sender.is_closed() {
+            break;
+        }
+        let client = Arc::clone(&client);
+        let sender = sender.clone();
+
+        tasks.spawn(async move {
+            let model = item.request.model.clone();
+            let result = client.request(item.request).await;
+            drop(permit);
+            if let Err(mpsc::error::SendError((id, _, result))) =
+                sender.send((item.id, model, result))
+            {
+                // Say which paid-for responses were not saved
+                match result.ok().and_then(|response| response.id) {
+                    Some(response_id) => eprintln!(
+                        "batch: result for {} ({}) not written; the output writer stopped",
+                        id, response_id
+                    ),
+                    None => eprintln!(
+                        "batch: result for {} not written; the output writer stopped",
+                        id
+                    ),
+                }
+            }
+        });
+    }
+    drop(sender);
+
+    let written = writer.await?;
+    // After a writer error, let requests in flight finish and report what was lost
+    while let Some(task) = tasks.join_next().await {
+        task?;
+    }
+    let (succeeded, failed, totals) = written?;
+    eprintln!(
+        "batch: {} succeeded, {} failed, {} skipped; {}",
+        succeeded,
+        failed,
+        skipped,
+        totals.summary()
+    );
+
+    if failed > 0 {
+        anyhow::bail!(
+            "{} requests failed; rerun the same command to retry them",
+            failed
+        );
+    }
+    Ok(())
+}
+
+type BatchResult = (String, String, anyhow::Result<gpt5::Gpt5Response>);
+
+/// Append results as they arrive, returning (succeeded, failed, usage)
+async fn write_results(
+    path: PathBuf,
+    mut receiver: mpsc::UnboundedReceiver<BatchResult>,
+    expected: usize,
+) -> anyhow::Result<(usize, usize, UsageTotals)> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open output {}", path.display()))?;
+    if ends_mid_line(&path)? {
+        writeln!(file)?;
+    }
+    let mut totals = UsageTotals::default();
+    let (mut succeeded, mut failed) = (0, 0);
+
+    while let Some((id, model, result)) = receiver.recv().await {
+        let record = match result {
+            Ok(response) => {
+                if let Some(usage) = &response.usage {
+                    totals.add(response.model.as_deref().unwrap_or(&model), usage);
+                }
+                match failure(&response) {
+                    None => {
+                        succeeded += 1;
+                        json!({ "id": id, "text": response.all_text().join("\n"), "response": response })
+                    }
+                    // Kept with an `error` so a rerun retries it
+                    Some(error) => {
+                        failed += 1;
+                        json!({ "id": id, "error": error, "response": response })
+                    }
+                }
+            }
+            Err(error) => {
+                failed += 1;
+                json!({ "id": id, "error": format!("{:#}", error) })
+            }
+        };
+
+        // One flushed line per result keeps the file resumable after a crash
+        writeln!(file, "{}", record)?;
+        file.flush()?;
+        eprint!("\rbatch: {}/{} done", succeeded + failed, expected);
+    }
+    if expected > 0 {
+        eprintln!();
+    }
+
+    Ok((succeeded, failed, totals))
+}
+
+/// Why a response that arrived with a success status still failed
+fn failure(response: &Gpt5Response) -> Option<String> {
+    if response.has_error() {
+        let error = response.error.as_ref()?;
+        let message = error.get("message").and_then(Value::as_str);
+        return Some(format!(
+            "GPT-5 reported an error: {}",
+            message.map_or_else(|| error.to_string(), str::to_string)
+        ));
+    }
+    if response.is_completed() {
+        return None;
+    }
+
+    let status = serde_json::to_value(&response.status).unwrap_or_default();
+    let reason = response
+        .incomplete_details
+        .as_ref()
+        .and_then(|details| details.get("reason"))
+        .and_then(Value::as_str);
+    Some(match (status.as_str(), reason) {
+        (Some(status), Some(reason)) => format!("GPT-5 response {}: {}", status, reason),
+        (Some(status), None) => format!("GPT-5 response {}", status),
+        (None, _) => "GPT-5 response has no status".to_string(),
+    })
+}
+
+/// Whether the file's last line was cut off without a newline
+fn ends_mid_line(path: &Path) -> anyhow::Result<bool> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(false);
+    }
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::End(-1))?;
+    file.read_exact(&mut last)?;
+    Ok(last[0] != b'\n')
+}
+
+/// Parse the input file, failing on the first malformed line
+fn read_items(path: &Path, request: &RequestArgs) -> anyhow::Result<Vec<BatchItem>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read input {}", path.display()))?;
+    let mut items = Vec::new();
+    let mut ids = HashSet::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value: Value = serde_json::from_str(line)
+            .with_context(|| format!("{}:{}: invalid JSON", path.display(), line_number))?;
+        let item = parse_item(value, line_number, request)
+            .with_context(|| format!("{}:{}", path.display(), line_number))?;
+
+        if !ids.insert(item.id.clone()) {
+            anyhow::bail!(
+                "{}:{}: duplicate id {}",
+                path.display(),
+                line_number,
+                item.id
+            );
+        }
+        items.push(item);
+    }
+
+    Ok(items)
+}
+
+fn parse_item(value: Value, line_number: usize, args: &RequestArgs) -> anyhow::Result<BatchItem> {
+    let default_id = format!("line-{}", line_number);
+
+    let mut object = match value {
+        Value::String(prompt) => {
+            return Ok(BatchItem {
+                id: default_id,
+                request: args.builder()?.input(&prompt).build(),
+            })
+        }
+        Value::Object(object) => object,
+        _ => anyhow::bail!("expected a string or an object"),
+    };
+
+    let id = match object.remove("id") {
+        Some(Value::String(id)) => id,
+        Some(Value::Number(id)) => id.to_string(),
+        Some(_) => anyhow::bail!("`id` must be a string or number"),
+        None => default_id,
+    };
+
+    let request = match object.remove("prompt") {
+        Some(Value::String(prompt)) => prompt_args(object, args)?.builder()?.input(&prompt).build(),
+        Some(_) => anyhow::bail!("`prompt` must be a string"),
+        None => serde_json::from_value(Value::Object(object))
+            .context("expected a `prompt` or a Gpt5Request object")?,
+    };
+
+    Ok(BatchItem { id, request })
+}
+
+/// Command-line flags with the overrides given next to a `prompt`
+fn prompt_args(overrides: Map<String, Value>, args: &RequestArgs) -> anyhow::Result<RequestArgs> {
+    let mut args = args.clone();
+    for (key, value) in overrides {
+        let text = || {
+            value
+                .as_str()
+                .map(str::to_string)
+                .with_context(|| format!("`{}` must be a string", key))
+        };
+        match key.as_str() {
+            "model" => args.model = text()?.parse()?,
+            "instructions" => args.instructions = Some(text()?),
+            "reasoning" => {
+                let effort = value
+                    .get("effort")
+                    .and_then(Value::as_str)
+                    .context("`reasoning` must be an object with an `effort`")?;
+                args.effort = Some(effort.to_string());
+            }
+            "max_output_tokens" => {
+                let tokens = value
+                    .as_u64()
+                    .and_then(|tokens| u32::try_from(tokens).ok())
+                    .context("`max_output_tokens` must be a positive integer")?;
+                args.max_tokens = Some(tokens);
+            }
+            _ => anyhow::bail!(
+                "unknown key `{}` next to `prompt` (expected model, instructions, reasoning or max_output_tokens)",
+                key
+            ),
+        }
+    }
+    Ok(args)
+}
+
+/// Ids with a successful result in an existing output file
+fn completed_ids(path: &Path) -> anyhow::Result<HashSet<String>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(HashSet::new()),
+        Err(error) => {
+            return Err(error).with_context(|| format!("Failed to read output {}", path.display()))
+        }
+    };
+
+    // A partially written last line (e.g. after a crash) is ignored
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|record| record.get("error").is_none())
+        .filter_map(|record| record.get("id")?.as_str().map(str::to_string))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Flags {
+        #[command(flatten)]
+        request: RequestArgs,
+    }
+
+    #[test]
+    fn test_prompt_overrides() {
+        let args = Flags::parse_from(["gpt5", "--effort", "high"]).request;
+        let item = parse_item(
+            json!({"id": "q1", "prompt": "Hi", "model": "gpt-5-mini", "reasoning": {"effort": "low"}}),
+            1,
+            &args,
+        )
+        .unwrap();
+        assert_eq!(item.request.model, "gpt-5-mini");
+        assert_eq!(
+            serde_json::to_value(&item.request.reasoning).unwrap()["effort"],
+            "low"
+        );
+
+        let error = parse_item(json!({"prompt": "Hi", "modle": "gpt-5"}), 2, &args)
+            .err()
+            .unwrap();
+        assert!(error.to_string().starts_with("unknown key `modle`"));
+    }
+}
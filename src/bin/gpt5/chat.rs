@@ -0,0 +1,371 @@
+//! Interactive multi-turn chat (`gpt5 chat`)
+//!
+//! Conversation context is kept server-side by chaining each request to the
+//! previous response with `previous_response_id`. Transcripts saved with
+//! `/save` record the messages and that id, so `/load` can pick the
+//! conversation back up.
+
+use crate::markdown::MarkdownRenderer;
+use crate::usage::UsageTotals;
+use crate::{parse_effort, parse_verbosity, RequestArgs};
+use anyhow::Context;
+use clap::Args;
+use gpt5::{Gpt5Client, Gpt5Model, Gpt5Response, Status, StreamEvent};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+const HELP: &str = "\
+Commands:
+  /model <name>        switch model (e.g. gpt-5, gpt-5-mini)
+  /effort <level>      set reasoning effort (minimal, low, medium, high)
+  /verbosity <level>   set output verbosity (low, medium, high)
+  /save <file>         save the transcript as JSON
+  /load <file>         load a transcript and continue it
+  /reset               start a new conversation
+  /usage               show token usage and estimated cost
+  /help                show this help
+  /quit                exit";
+
+/// Arguments for `gpt5 chat`
+#[derive(Debug, Args)]
+pub struct ChatArgs {
+    #[command(flatten)]
+    request: RequestArgs,
+
+    /// Resume a transcript saved with /save
+    #[arg(long, value_name = "FILE")]
+    load: Option<PathBuf>,
+
+    /// Wait for complete replies instead of streaming them
+    #[arg(long)]
+    no_stream: bool,
+
+    /// Print replies without Markdown styling
+    #[arg(long)]
+    plain: bool,
+}
+
+/// A single chat turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+/// Saved conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Transcript {
+    model: Gpt5Model,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    effort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verbosity: Option<String>,
+    /// Response the next turn continues from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_response_id: Option<String>,
+    messages: Vec<ChatMessage>,
+}
+
+struct ChatSession {
+    client: Gpt5Client,
+    request: RequestArgs,
+    previous_response_id: Option<String>,
+    messages: Vec<ChatMessage>,
+    totals: UsageTotals,
+    stream: bool,
+    renderer: Option<MarkdownRenderer>,
+}
+
+/// Run the chat loop until `/quit` or end of input
+pub async fn run(args: ChatArgs) -> anyhow::Result<()> {
+    let client = Gpt5Client::from_env()?;
+    let markdown = !args.plain && std::io::stdout().is_terminal();
+    let interactive = std::io::stdin().is_terminal();
+
+    let mut session = ChatSession {
+        client,
+        request: args.request,
+        previous_response_id: None,
+        messages: Vec::new(),
+        totals: UsageTotals::default(),
+        stream: !args.no_stream,
+        renderer: markdown.then(MarkdownRenderer::default),
+    };
+    if let Some(path) = &args.load {
+        session.load(path)?;
+    }
+
+    if interactive {
+        eprintln!(
+            "Chatting with {}. Type /help for commands, /quit to exit.",
+            session.request.model
+        );
+    }
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        if interactive {
+            eprint!("you> ");
+            std::io::stderr().flush()?;
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(command) = line.strip_prefix('/') {
+            match session.command(command) {
+                Ok(true) => continue,
+                Ok(false) => break,
+                Err(error) => {
+                    eprintln!("error: {:#}", error);
+                    continue;
+                }
+            }
+        }
+
+        if let Err(error) = session.send(line).await {
+            eprintln!("error: {:#}", error);
+        }
+    }
+
+    Ok(())
+}
+
+impl ChatSession {
+    /// Handle a slash command; returns `false` to end the session
+    fn command(&mut self, command: &str) -> anyhow::Result<bool> {
+        let (name, argument) = match command.split_once(char::is_whitespace) {
+            Some((name, argument)) => (name, argument.trim()),
+            None => (command, ""),
+        };
+        let required = |what: &str| -> anyhow::Result<&str> {
+            if argument.is_empty() {
+                anyhow::bail!("/{} needs {}", name, what);
+            }
+            Ok(argument)
+        };
+
+        match name {
+            "model" => {
+                self.request.model = required("a model name")?.parse()?;
+                eprintln!("model: {}", self.request.model);
+            }
+            "effort" => {
+                let level = required("a level")?;
+                parse_effort(level)?;
+                self.request.effort = Some(level.to_lowercase());
+                eprintln!("reasoning effort: {}", level);
+            }
+            "verbosity" => {
+                let level = required("a level")?;
+                parse_verbosity(level)?;
+                self.request.verbosity = Some(level.to_lowercase());
+                eprintln!("verbosity: {}", level);
+            }
+            "save" => {
+                let path = required("a file name")?;
+                self.save(Path::new(path))?;
+                eprintln!("saved {} messages to {}", self.messages.len(), path);
+            }
+            "load" => {
+                let path = required("a file name")?;
+                self.load(Path::new(path))?;
+                eprintln!("loaded {} messages from {}", self.messages.len(), path);
+            }
+            "reset" => {
+                self.previous_response_id = None;
+                self.messages.clear();
+                eprintln!("conversation reset");
+            }
+            "usage" => eprintln!("{}", self.totals.summary()),
+            "help" => eprintln!("{}", HELP),
+            "quit" | "exit" => return Ok(false),
+            other => anyhow::bail!("Unknown command /{} (try /help)", other),
+        }
+
+        Ok(true)
+    }
+
+    /// Send one user turn and print the reply
+    async fn send(&mut self, prompt: &str) -> anyhow::Result<()> {
+        let mut builder = self.request.builder()?.input(prompt);
+        if let Some(id) = &self.previous_response_id {
+            builder = builder.previous_response_id(id);
+        }
+        let request = builder.build();
+
+        let response = if self.stream {
+            self.stream_reply(request).await?
+        } else {
+            let response = self.client.request(request).await?;
+            let text = response.all_text().join("\n");
+            self.print_reply(&text)?;
+            check_reply(&response)?;
+            response
+        };
+
+        if let Some(usage) = &response.usage {
+            let model = response
+                .model
+                .as_deref()
+                .unwrap_or(self.request.model.as_str());
+            self.totals.add(model, usage);
+        }
+        self.messages.push(ChatMessage {
+            role: "user".to_string(),
+            content: prompt.to_string(),
+        });
+        self.messages.push(ChatMessage {
+            role: "assistant".to_string(),
+            content: response.all_text().join("\n"),
+        });
+        self.previous_response_id = response.id.clone();
+        Ok(())
+    }
+
+    async fn stream_reply(&mut self, request: gpt5::Gpt5Request) -> anyhow::Result<Gpt5Response> {
+        let mut stream = self.client.request_stream(request).await?;
+        let mut stdout = std::io::stdout();
+        let mut pending = String::new();
+        if let Some(renderer) = &mut self.renderer {
+            renderer.reset();
+        }
+
+        while let Some(event) = stream.next().await {
+            match event? {
+                StreamEvent::OutputTextDelta { delta, .. } => match &mut self.renderer {
+                    // Markdown is rendered a line at a time
+                    Some(renderer) => {
+                        pending.push_str(&delta);
+                        while let Some(newline) = pending.find('\n') {
+                            let line: String = pending.drain(..=newline).collect();
+                            writeln!(
+                                stdout,
+                                "{}",
+                                renderer.render_line(line.trim_end_matches('\n'))
+                            )?;
+                        }
+                    }
+                    None => {
+                        write!(stdout, "{}", delta)?;
+                        stdout.flush()?;
+                    }
+                },
+                StreamEvent::Completed(response) => {
+                    self.finish_line(&mut stdout, &pending)?;
+                    return Ok(response);
+                }
+                StreamEvent::Incomplete(response) => {
+                    self.finish_line(&mut stdout, &pending)?;
+                    anyhow::bail!(
+                        "GPT-5 response incomplete: {}",
+                        detail(&response.incomplete_details, "reason")
+                    )
+                }
+                StreamEvent::Failed(response) => {
+                    self.finish_line(&mut stdout, &pending)?;
+                    anyhow::bail!(
+                        "GPT-5 response failed: {}",
+                        detail(&response.error, "message")
+                    )
+                }
+                StreamEvent::Error { message, .. } => {
+                    anyhow::bail!("GPT-5 stream error: {}", message)
+                }
+                _ => {}
+            }
+        }
+
+        anyhow::bail!("GPT-5 stream ended without a final response")
+    }
+
+    /// End the streamed reply, rendering any unfinished Markdown line
+    fn finish_line(&mut self, stdout: &mut impl Write, pending: &str) -> anyhow::Result<()> {
+        match &mut self.renderer {
+            Some(renderer) if !pending.is_empty() => {
+                writeln!(stdout, "{}", renderer.render_line(pending))?
+            }
+            _ => writeln!(stdout)?,
+        }
+        Ok(())
+    }
+
+    fn print_reply(&mut self, text: &str) -> anyhow::Result<()> {
+        let mut stdout = std::io::stdout().lock();
+        match &mut self.renderer {
+            Some(renderer) => {
+                renderer.reset();
+                for line in text.lines() {
+                    writeln!(stdout, "{}", renderer.render_line(line))?;
+                }
+            }
+            None => writeln!(stdout, "{}", text)?,
+        }
+        Ok(())
+    }
+
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let transcript = Transcript {
+            model: self.request.model.clone(),
+            instructions: self.request.instructions.clone(),
+            effort: self.request.effort.clone(),
+            verbosity: self.request.verbosity.clone(),
+            previous_response_id: self.previous_response_id.clone(),
+            messages: self.messages.clone(),
+        };
+        let json = serde_json::to_string_pretty(&transcript)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write transcript {}", path.display()))
+    }
+
+    fn load(&mut self, path: &Path) -> anyhow::Result<()> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read transcript {}", path.display()))?;
+        let transcript: Transcript = serde_json::from_str(&json)
+            .with_context(|| format!("Invalid transcript {}", path.display()))?;
+
+        self.request.model = transcript.model;
+        self.request.instructions = transcript.instructions;
+        self.request.effort = transcript.effort;
+        self.request.verbosity = transcript.verbosity;
+        self.previous_response_id = transcript.previous_response_id;
+        self.messages = transcript.messages;
+        Ok(())
+    }
+}
+
+/// Fail for replies that report an error or did not complete
+fn check_reply(response: &Gpt5Response) -> anyhow::Result<()> {
+    if response.has_error() || response.status == Some(Status::Failed) {
+        anyhow::bail!(
+            "GPT-5 response failed: {}",
+            detail(&response.error, "message")
+        );
+    }
+    if !response.is_completed() {
+        anyhow::bail!(
+            "GPT-5 response incomplete: {}",
+            detail(&response.incomplete_details, "reason")
+        );
+    }
+    Ok(())
+}
+
+/// A string field of an error or incomplete-details object, for messages
+fn detail(value: &Option<serde_json::Value>, field: &str) -> String {
+    value
+        .as_ref()
+        .and_then(|value| value.get(field))
+        .and_then(|field| field.as_str())
+        .unwrap_or("no details given")
+        .to_string()
+}
@@ -0,0 +1,279 @@
+//! `gpt5` command-line tool
+//!
+//! Sends a prompt to GPT-5 and prints the reply, so shell pipelines can use
+//! the Responses API without writing Rust:
+//!
+//! ```text
+//! gpt5 "Summarize this" < notes.txt
+//! git diff | gpt5 -i "Write a commit message" --effort low --stream
+//! gpt5 -f question.md --json | jq .usage
+//! gpt5 chat -m gpt-5-mini
+//! gpt5 batch prompts.jsonl -o results.jsonl --concurrency 8 --rpm 500
+//! ```
+//!
+//! The client is configured from `OPENAI_API_KEY` and the other `OPENAI_*`
+//! variables read by `Gpt5Client::from_env`.
+
+mod batch;
+mod chat;
+mod markdown;
+mod usage;
+
+use anyhow::Context;
+use clap::{Args, Parser, Subcommand};
+use gpt5::{
+    Gpt5Client, Gpt5Model, Gpt5RequestBuilder, Gpt5Response, ReasoningEffort, StreamEvent,
+    VerbosityLevel,
+};
+use std::io::{IsTerminal, Read, Write};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "gpt5",
+    version,
+    about = "Query OpenAI GPT-5 models from the command line",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    request: RequestArgs,
+
+    /// Prompt text; read from stdin when omitted and stdin is not a terminal
+    prompt: Option<String>,
+
+    /// Read the prompt from a file (`-` for stdin)
+    #[arg(short, long, value_name = "PATH", conflicts_with = "prompt")]
+    file: Option<PathBuf>,
+
+    /// Print text as it is generated
+    #[arg(short, long)]
+    stream: bool,
+
+    /// Print the raw JSON response instead of its text
+    #[arg(long)]
+    json: bool,
+
+    /// Report token usage and estimated cost on stderr
+    #[arg(short, long)]
+    usage: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Start an interactive multi-turn chat
+    Chat(chat::ChatArgs),
+    /// Run a JSONL file of prompts or requests, resuming where a previous run stopped
+    Batch(batch::BatchArgs),
+}
+
+/// Options mapped onto `Gpt5RequestBuilder`
+#[derive(Debug, Clone, Args)]
+struct RequestArgs {
+    /// Model name, e.g. gpt-5, gpt-5-mini or gpt-5-nano
+    #[arg(short, long, default_value = "gpt-5-nano")]
+    model: Gpt5Model,
+
+    /// System instructions
+    #[arg(short, long)]
+    instructions: Option<String>,
+
+    /// Reasoning effort (minimal, low, medium, high)
+    #[arg(short, long, value_name = "LEVEL")]
+    effort: Option<String>,
+
+    /// Output verbosity (low, medium, high)
+    #[arg(short, long, value_name = "LEVEL")]
+    verbosity: Option<String>,
+
+    /// Maximum output tokens, including reasoning
+    #[arg(long, value_name = "N")]
+    max_tokens: Option<u32>,
+
+    /// Allow the model to search the web
+    #[arg(short, long)]
+    web_search: bool,
+
+    /// Ask for a JSON object as output
+    #[arg(long)]
+    json_output: bool,
+}
+
+impl RequestArgs {
+    /// Request builder with every flag applied except the input
+    fn builder(&self) -> anyhow::Result<Gpt5RequestBuilder> {
+        let mut builder = Gpt5RequestBuilder::new(self.model.clone());
+
+        if let Some(instructions) = &self.instructions {
+            builder = builder.instructions(instructions);
+        }
+        if let Some(effort) = &self.effort {
+            builder = builder.reasoning_effort(parse_effort(effort)?);
+        }
+        if let Some(verbosity) = &self.verbosity {
+            builder = builder.verbosity(parse_verbosity(verbosity)?);
+        }
+        if let Some(tokens) = self.max_tokens {
+            builder = builder.max_output_tokens(tokens);
+        }
+        if self.web_search {
+            builder = builder.web_search_enabled(true);
+        }
+        if self.json_output {
+            builder = builder.json_output();
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Parse a reasoning effort: minimal, low, medium or high
+fn parse_effort(value: &str) -> anyhow::Result<ReasoningEffort> {
+    match value.to_lowercase().as_str() {
+        "minimal" => Ok(ReasoningEffort::Minimal),
+        "low" => Ok(ReasoningEffort::Low),
+        "medium" => Ok(ReasoningEffort::Medium),
+        "high" => Ok(ReasoningEffort::High),
+        _ => anyhow::bail!(
+            "Invalid reasoning effort: {} (expected minimal, low, medium or high)",
+            value
+        ),
+    }
+}
+
+/// Parse a verbosity level: low, medium or high
+fn parse_verbosity(value: &str) -> anyhow::Result<VerbosityLevel> {
+    match value.to_lowercase().as_str() {
+        "low" => Ok(VerbosityLevel::Low),
+        "medium" => Ok(VerbosityLevel::Medium),
+        "high" => Ok(VerbosityLevel::High),
+        _ => anyhow::bail!(
+            "Invalid verbosity: {} (expected low, medium or high)",
+            value
+        ),
+    }
+}
+
+fn read_prompt(cli: &Cli) -> anyhow::Result<String> {
+    let prompt = match (&cli.prompt, &cli.file) {
+        (Some(prompt), _) => prompt.clone(),
+        (None, Some(path)) if path.as_os_str() == "-" => read_stdin()?,
+        (None, Some(path)) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read prompt from {}", path.display()))?,
+        (None, None) if !std::io::stdin().is_terminal() => read_stdin()?,
+        (None, None) => {
+            anyhow::bail!("No prompt given; pass it as an argument, with --file, or on stdin")
+        }
+    };
+
+    if prompt.trim().is_empty() {
+        anyhow::bail!("Prompt is empty");
+    }
+    Ok(prompt)
+}
+
+fn read_stdin() -> anyhow::Result<String> {
+    let mut prompt = String::new();
+    std::io::stdin()
+        .read_to_string(&mut prompt)
+        .context("Failed to read prompt from stdin")?;
+    Ok(prompt)
+}
+
+async fn run_once(cli: Cli) -> anyhow::Result<()> {
+    let client = Gpt5Client::from_env()?;
+    let prompt = read_prompt(&cli)?;
+    let request = cli.request.builder()?.input(&prompt).build();
+
+    let response = if cli.stream {
+        stream_response(&client, request, !cli.json).await?
+    } else {
+        client.request(request).await?
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    if cli.json {
+        serde_json::to_writer_pretty(&mut stdout, &response)?;
+        writeln!(stdout)?;
+    } else if !cli.stream {
+        writeln!(stdout, "{}", response.all_text().join("\n"))?;
+    }
+
+    if cli.usage {
+        if let Some(usage) = &response.usage {
+            let model = response
+                .model
+                .as_deref()
+                .unwrap_or(cli.request.model.as_str());
+            eprintln!("{}", usage::describe(model, usage));
+        }
+    }
+
+    if response.has_error() {
+        anyhow::bail!("GPT-5 reported an error: {:?}", response.error);
+    }
+    Ok(())
+}
+
+/// Stream a response, optionally echoing text deltas to stdout
+async fn stream_response(
+    client: &Gpt5Client,
+    request: gpt5::Gpt5Request,
+    echo: bool,
+) -> anyhow::Result<Gpt5Response> {
+    let mut stream = client.request_stream(request).await?;
+    let mut stdout = std::io::stdout();
+
+    while let Some(event) = stream.next().await {
+        match event? {
+            StreamEvent::OutputTextDelta { delta, .. } if echo => {
+                write!(stdout, "{}", delta)?;
+                stdout.flush()?;
+            }
+            StreamEvent::Completed(response)
+            | StreamEvent::Incomplete(response)
+            | StreamEvent::Failed(response) => {
+                if echo {
+                    writeln!(stdout)?;
+                }
+                return Ok(response);
+            }
+            StreamEvent::Error { message, .. } => anyhow::bail!("GPT-5 stream error: {}", message),
+            _ => {}
+        }
+    }
+
+    anyhow::bail!("GPT-5 stream ended without a final response")
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let mut cli = Cli::parse();
+    match cli.command.take() {
+        Some(Command::Chat(args)) => chat::run(args).await,
+        Some(Command::Batch(args)) => batch::run(args).await,
+        None => run_once(cli).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_levels() {
+        assert_eq!(parse_effort("Minimal").unwrap(), ReasoningEffort::Minimal);
+        assert_eq!(parse_effort("high").unwrap(), ReasoningEffort::High);
+        assert_eq!(parse_verbosity("LOW").unwrap(), VerbosityLevel::Low);
+
+        let error = parse_effort("extreme").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Invalid reasoning effort: extreme"));
+        assert!(parse_verbosity("minimal").is_err());
+        assert!(parse_verbosity("").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+//! Line-oriented Markdown rendering with ANSI escapes
+//!
+//! Rendering works one complete line at a time so streamed text can be
+//! displayed as soon as each line ends.
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+const BOLD: &str = "\x1b[1m";
+const UNDERLINE: &str = "\x1b[4m";
+const ITALIC: &str = "\x1b[3m";
+const CYAN: &str = "\x1b[36m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders Markdown lines, remembering whether it is inside a code block
+#[derive(Debug, Default)]
+pub struct MarkdownRenderer {
+    in_code_block: bool,
+}
+
+impl MarkdownRenderer {
+    /// Render a single line (without its trailing newline)
+    pub fn render_line(&mut self, line: &str) -> String {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            self.in_code_block = !self.in_code_block;
+            return format!("{}{}{}", DIM, line, RESET);
+        }
+        if self.in_code_block {
+            return format!("{}{}{}", CYAN, line, RESET);
+        }
+
+        if let Some(heading) = heading_text(trimmed) {
+            return format!("{}{}{}{}", BOLD, UNDERLINE, render_inline(heading), RESET);
+        }
+        if let Some(quote) = trimmed.strip_prefix("> ") {
+            return format!("{}│ {}{}", DIM, render_inline(quote), RESET);
+        }
+        for bullet in ["- ", "* ", "+ "] {
+            if let Some(item) = trimmed.strip_prefix(bullet) {
+                let indent = &line[..line.len() - trimmed.len()];
+                return format!("{}  • {}", indent, render_inline(item));
+            }
+        }
+
+        render_inline(line)
+    }
+
+    /// Reset state between replies
+    pub fn reset(&mut self) {
+        self.in_code_block = false;
+    }
+}
+
+fn heading_text(line: &str) -> Option<&str> {
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&hashes) {
+        line[hashes..].strip_prefix(' ')
+    } else {
+        None
+    }
+}
+
+/// Apply inline code, bold and italic styling
+fn render_inline(text: &str) -> String {
+    static PATTERNS: OnceLock<[(Regex, String); 3]> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| {
+        [
+            (
+                Regex::new(r"`([^`]+)`").expect("valid regex"),
+                format!("{}$1{}", CYAN, RESET),
+            ),
+            (
+                Regex::new(r"\*\*([^*]+)\*\*|__([^_]+)__").expect("valid regex"),
+                format!("{}$1$2{}", BOLD, RESET),
+            ),
+            (
+                Regex::new(r"\*([^*\s][^*]*)\*").expect("valid regex"),
+                format!("{}$1{}", ITALIC, RESET),
+            ),
+        ]
+    });
+
+    let mut rendered = text.to_string();
+    for (pattern, replacement) in patterns {
+        rendered = pattern
+            .replace_all(&rendered, replacement.as_str())
+            .into_owned();
+    }
+    rendered
+}
@@ -0,0 +1,58 @@
+//! Token usage and cost reporting
+
+use gpt5::{ModelInfo, ResponseUsage};
+
+/// Estimated cost of a call in USD, if the model's pricing is known
+fn cost(model: &str, usage: &ResponseUsage) -> Option<f64> {
+    ModelInfo::lookup(model).map(|info| info.pricing.cost(usage))
+}
+
+/// Running totals across several calls
+#[derive(Debug, Default, Clone)]
+pub struct UsageTotals {
+    calls: u32,
+    input_tokens: u64,
+    output_tokens: u64,
+    reasoning_tokens: u64,
+    cost: f64,
+}
+
+impl UsageTotals {
+    pub fn add(&mut self, model: &str, usage: &ResponseUsage) {
+        self.calls += 1;
+        self.input_tokens += u64::from(usage.input_tokens);
+        self.output_tokens += u64::from(usage.output_tokens);
+        self.reasoning_tokens += u64::from(reasoning_tokens(usage));
+        self.cost += cost(model, usage).unwrap_or(0.0);
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "usage: {} calls, {} input / {} output tokens ({} reasoning), ~${:.6}",
+            self.calls, self.input_tokens, self.output_tokens, self.reasoning_tokens, self.cost
+        )
+    }
+}
+
+fn reasoning_tokens(usage: &ResponseUsage) -> u32 {
+    usage
+        .output_tokens_details
+        .as_ref()
+        .and_then(|details| details.reasoning_tokens)
+        .unwrap_or(0)
+}
+
+/// One-line usage report for a single call
+pub fn describe(model: &str, usage: &ResponseUsage) -> String {
+    let cost = match cost(model, usage) {
+        Some(cost) => format!("~${:.6}", cost),
+        None => "cost unknown".to_string(),
+    };
+    format!(
+        "usage: {} input / {} output tokens ({} reasoning), {}",
+        usage.input_tokens,
+        usage.output_tokens,
+        reasoning_tokens(usage),
+        cost
+    )
+}
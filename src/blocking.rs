@@ -0,0 +1,173 @@
+//! Synchronous wrapper around [`crate::Gpt5Client`]
+//!
+//! Enabled with the `blocking` feature. Each blocking client owns a small
+//! tokio runtime and drives the async client on it, so the same
+//! `Gpt5Request` and `Gpt5Response` types are used on both sides.
+//!
+//! Do not call these methods from inside an async runtime: tokio panics when
+//! a runtime is blocked on from one of its own worker threads.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use gpt5::blocking::Gpt5Client;
+//! use gpt5::Gpt5Model;
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     let client = Gpt5Client::from_env()?;
+//!     let answer = client.simple(Gpt5Model::Gpt5Nano, "What is the capital of France?")?;
+//!     println!("{}", answer);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::catalog::ModelObject;
+use crate::models::Gpt5Model;
+use crate::options::RequestOptions;
+use crate::requests::Gpt5Request;
+use crate::responses::Gpt5Response;
+use crate::streaming::StreamEvent;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking GPT-5 client
+///
+/// Configure the async [`crate::Gpt5Client`] (middleware, caching, Azure,
+/// builder settings) and wrap it with [`Gpt5Client::from_async`], or use
+/// [`Gpt5Client::new`] for the defaults.
+#[derive(Debug)]
+pub struct Gpt5Client {
+    inner: crate::Gpt5Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Gpt5Client {
+    /// Create a blocking client with the specified API key
+    pub fn new(api_key: String) -> anyhow::Result<Self> {
+        Self::from_async(crate::Gpt5Client::new(api_key))
+    }
+
+    /// Create a blocking client from `OPENAI_*` environment variables
+    ///
+    /// See `gpt5::Gpt5Client::from_env` for the variables read.
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_async(crate::Gpt5Client::from_env()?)
+    }
+
+    /// Wrap a configured async client
+    pub fn from_async(client: crate::Gpt5Client) -> anyhow::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| anyhow::anyhow!("Failed to start tokio runtime: {}", error))?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The wrapped async client
+    pub fn inner(&self) -> &crate::Gpt5Client {
+        &self.inner
+    }
+
+    /// Send a request and wait for the response
+    pub fn request(&self, req: Gpt5Request) -> anyhow::Result<Gpt5Response> {
+        self.runtime.block_on(self.inner.request(req))
+    }
+
+    /// Send a request with per-call overrides and wait for the response
+    pub fn request_with_options(
+        &self,
+        req: Gpt5Request,
+        options: RequestOptions,
+    ) -> anyhow::Result<Gpt5Response> {
+        self.runtime
+            .block_on(self.inner.request_with_options(req, options))
+    }
+
+    /// Send a prompt and return the first text output
+    pub fn simple(&self, model: Gpt5Model, prompt: &str) -> anyhow::Result<String> {
+        self.runtime.block_on(self.inner.simple(model, prompt))
+    }
+
+    /// List the models available to this API key
+    pub fn list_models(&self) -> anyhow::Result<Vec<ModelObject>> {
+        self.runtime.block_on(self.inner.list_models())
+    }
+
+    /// Fetch a single model by name
+    pub fn retrieve_model(&self, id: &str) -> anyhow::Result<ModelObject> {
+        self.runtime.block_on(self.inner.retrieve_model(id))
+    }
+
+    /// Confirm `model` exists for this API key
+    pub fn ensure_model_available(&self, model: &Gpt5Model) -> anyhow::Result<ModelObject> {
+        self.runtime
+            .block_on(self.inner.ensure_model_available(model))
+    }
+
+    /// Stream a response as an iterator of events
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use gpt5::blocking::Gpt5Client;
+    /// use gpt5::{Gpt5Model, Gpt5RequestBuilder, StreamEvent};
+    ///
+    /// fn main() -> anyhow::Result<()> {
+    ///     let client = Gpt5Client::from_env()?;
+    ///     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///         .input("Write a haiku about Rust")
+    ///         .build();
+    ///
+    ///     for event in client.request_stream(request)? {
+    ///         if let StreamEvent::OutputTextDelta { delta, .. } = event? {
+    ///             print!("{}", delta);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn request_stream(&self, req: Gpt5Request) -> anyhow::Result<ResponseStream> {
+        self.request_stream_with_options(req, RequestOptions::default())
+    }
+
+    /// Stream a response with per-call overrides
+    pub fn request_stream_with_options(
+        &self,
+        req: Gpt5Request,
+        options: RequestOptions,
+    ) -> anyhow::Result<ResponseStream> {
+        let stream = self
+            .runtime
+            .block_on(self.inner.request_stream_with_options(req, options))?;
+
+        Ok(ResponseStream {
+            inner: stream,
+            runtime: Arc::clone(&self.runtime),
+        })
+    }
+}
+
+/// Blocking iterator over the events of a streamed response
+pub struct ResponseStream {
+    inner: crate::streaming::ResponseStream,
+    runtime: Arc<Runtime>,
+}
+
+impl ResponseStream {
+    /// Consume the stream and return the final response
+    pub fn final_response(self) -> anyhow::Result<Gpt5Response> {
+        self.runtime.block_on(self.inner.final_response())
+    }
+}
+
+impl Iterator for ResponseStream {
+    type Item = anyhow::Result<StreamEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
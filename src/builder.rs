@@ -0,0 +1,232 @@
+//! Builder for `Gpt5Client` with HTTP transport settings
+
+use crate::client::Gpt5Client;
+use crate::secret::SecretString;
+use reqwest::{Certificate, Client, Proxy};
+use std::time::Duration;
+
+/// User agent sent when none is configured
+pub(crate) const DEFAULT_USER_AGENT: &str = concat!("gpt5-rust/", env!("CARGO_PKG_VERSION"));
+
+/// Default total timeout for a request
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Builder for a [`Gpt5Client`] with a tuned HTTP client
+///
+/// Unlike `Gpt5Client::new`, [`build`](Gpt5ClientBuilder::build) reports
+/// invalid settings (a malformed proxy URL or certificate, a TLS backend
+/// failure) as an error instead of falling back to a default HTTP client.
+///
+/// HTTP/2 prior knowledge, gzip and brotli require the `http2`, `gzip` and
+/// `brotli` crate features respectively.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::Gpt5Client;
+/// use std::time::Duration;
+///
+/// let client = Gpt5Client::builder("sk-...")
+///     .connect_timeout(Duration::from_secs(5))
+///     .read_timeout(Duration::from_secs(120))
+///     .pool_max_idle_per_host(8)
+///     .pool_idle_timeout(Duration::from_secs(30))
+///     .user_agent("my-app/1.0")
+///     .build()
+///     .expect("valid client configuration");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gpt5ClientBuilder {
+    api_key: SecretString,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http1_only: bool,
+    #[cfg(feature = "http2")]
+    http2_prior_knowledge: bool,
+    proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    user_agent: String,
+    #[cfg(feature = "gzip")]
+    gzip: bool,
+    #[cfg(feature = "brotli")]
+    brotli: bool,
+}
+
+impl Gpt5ClientBuilder {
+    /// Start a builder with the same defaults as `Gpt5Client::new`
+    pub fn new(api_key: impl Into<SecretString>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: None,
+            timeout: Some(DEFAULT_TIMEOUT),
+            connect_timeout: None,
+            read_timeout: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http1_only: false,
+            #[cfg(feature = "http2")]
+            http2_prior_knowledge: false,
+            proxy: None,
+            root_certificates: Vec::new(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            #[cfg(feature = "gzip")]
+            gzip: true,
+            #[cfg(feature = "brotli")]
+            brotli: true,
+        }
+    }
+
+    /// Set the API base URL
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Total time allowed for a request, including reading the body (default 60s)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Remove the total request timeout, e.g. for long streams
+    pub fn no_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Time allowed to establish a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum time to wait between reads from the server
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum number of idle connections kept per host
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long idle pooled connections are kept open
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Only speak HTTP/1.1
+    pub fn http1_only(mut self) -> Self {
+        self.http1_only = true;
+        self
+    }
+
+    /// Speak HTTP/2 without negotiating it first (requires the `http2` feature)
+    #[cfg(feature = "http2")]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Route all traffic through a proxy, e.g. `http://proxy.internal:3128`
+    ///
+    /// The URL is validated by [`build`](Gpt5ClientBuilder::build).
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.proxy = Some(url.to_string());
+        self
+    }
+
+    /// Trust an additional root certificate in PEM format
+    ///
+    /// The certificate is parsed by [`build`](Gpt5ClientBuilder::build).
+    pub fn root_certificate_pem(mut self, pem: &[u8]) -> Self {
+        self.root_certificates.push(pem.to_vec());
+        self
+    }
+
+    /// Override the `User-Agent` header (default `gpt5-rust/<version>`)
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Enable or disable gzip response decompression (requires the `gzip` feature)
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.gzip = enable;
+        self
+    }
+
+    /// Enable or disable brotli response decompression (requires the `brotli` feature)
+    #[cfg(feature = "brotli")]
+    pub fn brotli(mut self, enable: bool) -> Self {
+        self.brotli = enable;
+        self
+    }
+
+    /// Build the client, failing on invalid settings
+    pub fn build(self) -> anyhow::Result<Gpt5Client> {
+        let mut builder = Client::builder().user_agent(self.user_agent);
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if self.http1_only {
+            builder = builder.http1_only();
+        }
+        #[cfg(feature = "http2")]
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(url) = &self.proxy {
+            let proxy = Proxy::all(url)
+                .map_err(|error| anyhow::anyhow!("Invalid proxy URL {}: {}", url, error))?;
+            builder = builder.proxy(proxy);
+        }
+        for pem in &self.root_certificates {
+            let certificate = Certificate::from_pem(pem)
+                .map_err(|error| anyhow::anyhow!("Invalid root certificate: {}", error))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        #[cfg(feature = "gzip")]
+        {
+            builder = builder.gzip(self.gzip);
+        }
+        #[cfg(feature = "brotli")]
+        {
+            builder = builder.brotli(self.brotli);
+        }
+
+        let client = builder
+            .build()
+            .map_err(|error| match std::error::Error::source(&error) {
+                Some(cause) => anyhow::anyhow!("Failed to build HTTP client: {}: {}", error, cause),
+                None => anyhow::anyhow!("Failed to build HTTP client: {}", error),
+            })?;
+
+        let client = Gpt5Client::from_http_client(client, self.api_key);
+        Ok(match self.base_url {
+            Some(base_url) => client.with_base_url(base_url),
+            None => client,
+        })
+    }
+}
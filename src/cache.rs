@@ -0,0 +1,362 @@
+//! Response caching keyed by request content
+//!
+//! `ResponseCache` is a middleware layer that remembers completed responses
+//! and replays them when an identical request is sent again. Requests are
+//! keyed by a stable hash of their serialized JSON body, so the key only
+//! changes when something sent to the API changes (model, input, instructions,
+//! tools, reasoning, text options or extra parameters). Inside a client the
+//! key also covers the client's base URL, endpoint, organization and project,
+//! so clients for different accounts can share one cache. Each entry keeps
+//! the request it answers and is only returned for that exact request.
+//!
+//! Calls that override the API key through `RequestOptions::api_key` skip
+//! the cache entirely, so one tenant's responses are never replayed to another.
+//! Background requests are never cached, and only responses with status
+//! `completed` are stored.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::{Gpt5Client, ResponseCache};
+//! use std::time::Duration;
+//!
+//! let cache = ResponseCache::in_memory(1_000).with_ttl(Duration::from_secs(3600));
+//! let client = Gpt5Client::new("sk-...".to_string()).with_cache(cache.clone());
+//!
+//! // ... send requests ...
+//! println!("cache hits: {}", cache.stats().hits);
+//! ```
+
+use crate::client::Gpt5Client;
+use crate::enums::Status;
+use crate::middleware::{BoxFuture, Middleware, MiddlewareRequest, Next};
+use crate::requests::Gpt5Request;
+use crate::responses::Gpt5Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Cache of GPT-5 responses with an in-memory LRU or on-disk backend
+///
+/// Cloning a `ResponseCache` is cheap and yields a handle to the same
+/// storage and counters, so one clone can be given to the client while
+/// another is kept around to read statistics.
+#[derive(Clone)]
+pub struct ResponseCache {
+    inner: Arc<CacheInner>,
+    ttl: Option<Duration>,
+}
+
+/// Hit and miss counters for a `ResponseCache`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Requests answered from the cache
+    pub hits: u64,
+    /// Requests that had to be sent to the API
+    pub misses: u64,
+}
+
+struct CacheInner {
+    backend: Backend,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+enum Backend {
+    Memory(Mutex<MemoryStore>),
+    Disk(PathBuf),
+}
+
+struct MemoryStore {
+    capacity: usize,
+    entries: HashMap<String, CacheEntry>,
+    order: VecDeque<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Unix timestamp (milliseconds) when the entry was written
+    stored_at: u64,
+    /// Canonical form of the request this entry answers
+    #[serde(default)]
+    request: String,
+    response: Gpt5Response,
+}
+
+impl ResponseCache {
+    /// Create an in-memory cache holding at most `capacity` responses
+    ///
+    /// The least recently used entry is evicted once the cache is full.
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::with_backend(Backend::Memory(Mutex::new(MemoryStore {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        })))
+    }
+
+    /// Create a cache that stores one JSON file per response in `dir`
+    ///
+    /// The directory is created on first write. Entries survive restarts,
+    /// which makes this backend a good fit for repeated evaluation runs.
+    pub fn on_disk(dir: impl Into<PathBuf>) -> Self {
+        Self::with_backend(Backend::Disk(dir.into()))
+    }
+
+    fn with_backend(backend: Backend) -> Self {
+        Self {
+            inner: Arc::new(CacheInner {
+                backend,
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+            }),
+            ttl: None,
+        }
+    }
+
+    /// Treat entries older than `ttl` as missing
+    ///
+    /// The TTL belongs to this handle, so set it before cloning the cache
+    /// into a client.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Compute the cache key for a request used outside a client
+    ///
+    /// The key is a hex-encoded 64-bit FNV-1a hash of the request's JSON
+    /// body with object keys sorted, so it is stable across processes and
+    /// independent of the order extra parameters were added in.
+    pub fn key(request: &Gpt5Request) -> String {
+        key_of(&canonical(&Value::Null, request))
+    }
+
+    /// Look up a cached response for the request
+    ///
+    /// Returned responses have `from_cache` set. Updates the hit/miss counters.
+    /// Entries written by a client are keyed by that client too, so they are
+    /// only found through the client.
+    pub fn get(&self, request: &Gpt5Request) -> Option<Gpt5Response> {
+        self.lookup(&canonical(&Value::Null, request))
+    }
+
+    /// Store a response for the request, replacing any previous entry
+    pub fn put(&self, request: &Gpt5Request, response: &Gpt5Response) {
+        self.store(canonical(&Value::Null, request), response);
+    }
+
+    fn lookup(&self, canonical: &str) -> Option<Gpt5Response> {
+        let key = key_of(canonical);
+        let entry = match &self.inner.backend {
+            Backend::Memory(store) => store.lock().ok().and_then(|mut store| store.get(&key)),
+            Backend::Disk(dir) => read_disk_entry(dir, &key),
+        }
+        // A different request with the same 64-bit hash is a miss
+        .filter(|entry| entry.request == canonical && !self.is_expired(entry));
+
+        match entry {
+            Some(entry) => {
+                self.inner.hits.fetch_add(1, Ordering::Relaxed);
+                let mut response = entry.response;
+                response.from_cache = true;
+                Some(response)
+            }
+            None => {
+                self.inner.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    fn store(&self, canonical: String, response: &Gpt5Response) {
+        let key = key_of(&canonical);
+        let entry = CacheEntry {
+            stored_at: now_millis(),
+            request: canonical,
+            response: response.clone(),
+        };
+
+        match &self.inner.backend {
+            Backend::Memory(store) => {
+                if let Ok(mut store) = store.lock() {
+                    store.insert(key, entry);
+                }
+            }
+            Backend::Disk(dir) => {
+                if let Err(error) = write_disk_entry(dir, &key, &entry) {
+                    tracing::warn!("ResponseCache: failed to write cache entry: {}", error);
+                }
+            }
+        }
+    }
+
+    /// Remove every cached entry
+    pub fn clear(&self) {
+        match &self.inner.backend {
+            Backend::Memory(store) => {
+                if let Ok(mut store) = store.lock() {
+                    store.entries.clear();
+                    store.order.clear();
+                }
+            }
+            Backend::Disk(dir) => {
+                let Ok(files) = std::fs::read_dir(dir) else {
+                    return;
+                };
+                for file in files.flatten() {
+                    let path = file.path();
+                    if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+                        let _ = std::fs::remove_file(path);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Current hit/miss counters
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.inner.hits.load(Ordering::Relaxed),
+            misses: self.inner.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn is_expired(&self, entry: &CacheEntry) -> bool {
+        match self.ttl {
+            Some(ttl) => {
+                u128::from(now_millis().saturating_sub(entry.stored_at)) >= ttl.as_millis()
+            }
+            None => false,
+        }
+    }
+}
+
+impl Middleware for ResponseCache {
+    fn handle<'a>(
+        &'a self,
+        req: MiddlewareRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+        Box::pin(async move {
+            // Per-request keys usually mean per-tenant calls; never share their responses
+            if req.api_key.is_some() {
+                return next.run(req).await;
+            }
+
+            // Background responses come back queued and finish later
+            if req.request.background == Some(true) {
+                return next.run(req).await;
+            }
+
+            let canonical = canonical(&origin(next.client()), &req.request);
+            if !req.request.bypass_cache {
+                if let Some(response) = self.lookup(&canonical) {
+                    return Ok(response);
+                }
+            }
+
+            let response = next.run(req).await?;
+            // Incomplete replies were cut short, e.g. by `max_output_tokens`
+            if !response.has_error() && response.status == Some(Status::Completed) {
+                self.store(canonical, &response);
+            }
+            Ok(response)
+        })
+    }
+}
+
+impl MemoryStore {
+    fn get(&mut self, key: &str) -> Option<CacheEntry> {
+        let entry = self.entries.get(key)?.clone();
+        self.touch(key);
+        Some(entry)
+    }
+
+    fn insert(&mut self, key: String, entry: CacheEntry) {
+        if self.entries.insert(key.clone(), entry).is_some() {
+            self.touch(&key);
+            return;
+        }
+
+        self.order.push_back(key);
+        while self.entries.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|existing| existing == key) {
+            if let Some(existing) = self.order.remove(position) {
+                self.order.push_back(existing);
+            }
+        }
+    }
+}
+
+fn read_disk_entry(dir: &Path, key: &str) -> Option<CacheEntry> {
+    let contents = std::fs::read_to_string(dir.join(format!("{}.json", key))).ok()?;
+    serde_json::from_str(&contents)
+        .map_err(|error| {
+            tracing::warn!(
+                "ResponseCache: ignoring unreadable cache entry {}: {}",
+                key,
+                error
+            );
+        })
+        .ok()
+}
+
+fn write_disk_entry(dir: &Path, key: &str, entry: &CacheEntry) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.json", key));
+    let temp = dir.join(format!("{}.json.tmp", key));
+    std::fs::write(&temp, serde_json::to_vec(entry)?)?;
+    std::fs::rename(temp, path)?;
+    Ok(())
+}
+
+/// Settings that decide which endpoint and account answer a client's requests
+fn origin(client: &Gpt5Client) -> Value {
+    json!({
+        "base_url": client.base_url,
+        "endpoint": format!("{:?}", client.endpoint),
+        "organization": client.organization,
+        "project": client.project,
+    })
+}
+
+/// Request body and origin as sorted JSON, the text the cache key hashes
+fn canonical(origin: &Value, request: &Gpt5Request) -> String {
+    let request = serde_json::to_value(request).unwrap_or_default();
+    json!({ "origin": origin, "request": request }).to_string()
+}
+
+fn key_of(canonical: &str) -> String {
+    format!("{:016x}", fnv1a64(canonical.as_bytes()))
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
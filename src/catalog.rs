@@ -0,0 +1,63 @@
+//! Models available to an API key (`/v1/models`)
+
+use crate::models::Gpt5Model;
+use crate::registry::ModelInfo;
+use serde::{Deserialize, Serialize};
+
+/// A model returned by the models endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelObject {
+    /// Model name, e.g. `gpt-5-mini-2025-08-07` or a fine-tune id
+    pub id: String,
+    /// Always `model`
+    #[serde(default)]
+    pub object: String,
+    /// Unix timestamp of when the model was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<u64>,
+    /// Organization that owns the model (`openai`, `system`, or yours for fine-tunes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owned_by: Option<String>,
+}
+
+/// Page of models as returned by `GET /v1/models`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelList {
+    /// Always `list`
+    #[serde(default)]
+    pub object: String,
+    /// Models visible to the API key
+    pub data: Vec<ModelObject>,
+}
+
+impl ModelObject {
+    /// The model as a `Gpt5Model`, using a named variant where one exists
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Model, ModelObject};
+    ///
+    /// let object = ModelObject {
+    ///     id: "gpt-5-mini".to_string(),
+    ///     object: "model".to_string(),
+    ///     created: None,
+    ///     owned_by: Some("system".to_string()),
+    /// };
+    /// assert!(matches!(object.to_gpt5_model(), Gpt5Model::Gpt5Mini));
+    /// ```
+    pub fn to_gpt5_model(&self) -> Gpt5Model {
+        Gpt5Model::from_name(&self.id)
+    }
+
+    /// Registry entry for this model, if it belongs to the GPT-5 family
+    pub fn info(&self) -> Option<&'static ModelInfo> {
+        ModelInfo::lookup(&self.id)
+    }
+}
+
+impl From<ModelObject> for Gpt5Model {
+    fn from(object: ModelObject) -> Self {
+        Gpt5Model::from_name(&object.id)
+    }
+}
@@ -0,0 +1,291 @@
+//! Rendering cited text with numbered footnotes
+//!
+//! Each distinct cited URL gets one footnote number, in order of first
+//! appearance, and is listed once under the text. When the API writes the
+//! citation inline (`([example.com](https://example.com/page))`) the inline
+//! link is replaced by the footnote marker; otherwise the marker follows
+//! the cited span. Only `http` and `https` sources become links; any other
+//! URL is listed as text.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::{CitationFormat, Gpt5Response};
+//! use serde_json::json;
+//!
+//! let response: Gpt5Response = serde_json::from_value(json!({
+//!     "output": [{
+//!         "type": "message",
+//!         "content": [{
+//!             "type": "output_text",
+//!             "text": "Rust 1.90 is out.",
+//!             "annotations": [{
+//!                 "type": "url_citation",
+//!                 "start_index": 0,
+//!                 "end_index": 17,
+//!                 "url": "https://blog.rust-lang.org/",
+//!                 "title": "Rust Blog"
+//!             }]
+//!         }]
+//!     }]
+//! }))
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     response.render_citations(CitationFormat::PlainText),
+//!     "Rust 1.90 is out. [1]\n\nSources:\n[1] Rust Blog - https://blog.rust-lang.org/"
+//! );
+//! ```
+
+use crate::enums::{ContentType, OutputType};
+use crate::responses::{Annotation, Gpt5Response, OutputContent};
+use std::fmt::Write;
+
+/// Output format for [`Gpt5Response::render_citations`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CitationFormat {
+    /// `[^1]` footnote references with `[^1]: [Title](url)` definitions
+    Markdown,
+    /// Escaped text with `<sup>` links to an ordered source list
+    Html,
+    /// `[1]` markers with a `Sources:` list
+    PlainText,
+}
+
+/// A cited source; its footnote number is its position in the list plus one
+struct Source<'a> {
+    url: &'a str,
+    title: Option<&'a str>,
+}
+
+impl OutputContent {
+    /// This segment's text with numbered footnotes for its URL citations
+    ///
+    /// Returns an empty string for content without text.
+    pub fn render_citations(&self, format: CitationFormat) -> String {
+        match &self.text {
+            Some(text) => render(&[(text, self.annotations.as_deref())], format),
+            None => String::new(),
+        }
+    }
+}
+
+impl Gpt5Response {
+    /// All output text with numbered footnotes for its URL citations
+    ///
+    /// Text segments are joined with blank lines and share one source list.
+    pub fn render_citations(&self, format: CitationFormat) -> String {
+        let segments: Vec<(&str, Option<&[Annotation]>)> = self
+            .output
+            .iter()
+            .flatten()
+            .filter(|output| output.output_type == OutputType::Message)
+            .flat_map(|output| output.content.iter().flatten())
+            .filter(|content| content.content_type == ContentType::OutputText)
+            .filter_map(|content| Some((content.text.as_deref()?, content.annotations.as_deref())))
+            .collect();
+        render(&segments, format)
+    }
+}
+
+fn render(segments: &[(&str, Option<&[Annotation]>)], format: CitationFormat) -> String {
+    let mut sources: Vec<Source> = Vec::new();
+    let mut body = Vec::new();
+
+    for (text, annotations) in segments {
+        let chars: Vec<char> = text.chars().collect();
+
+        let mut cited: Vec<(usize, usize, &str, Option<&str>)> = annotations
+            .iter()
+            .copied()
+            .flatten()
+            .filter_map(|annotation| match annotation {
+                Annotation::UrlCitation {
+                    start_index,
+                    end_index,
+                    url,
+                    title,
+                } => Some((
+                    *start_index as usize,
+                    *end_index as usize,
+                    url.as_str(),
+                    title.as_deref(),
+                )),
+                _ => None,
+            })
+            .filter(|(start, end, _, _)| start <= end && *end <= chars.len())
+            .collect();
+        cited.sort_by_key(|(start, end, _, _)| (*start, *end));
+
+        // (start, end, footnote), ordered by position
+        let mut spans: Vec<(usize, usize, usize)> = Vec::new();
+        for (start, end, url, title) in cited {
+            let number = match sources.iter().position(|source| source.url == url) {
+                Some(index) => index + 1,
+                None => {
+                    sources.push(Source { url, title });
+                    sources.len()
+                }
+            };
+            spans.push((start, end, number));
+        }
+
+        let mut rendered = String::new();
+        let mut position = 0;
+        for (start, end, number) in spans {
+            let url = sources[number - 1].url;
+            let cited: String = chars[start..end].iter().collect();
+
+            // Inline citation links are replaced; overlapping spans only get a marker
+            if start >= position && is_inline_link(&cited, url) {
+                let (start, end) = widen_to_parentheses(&chars, start, end, position);
+                let before = chars[position..start].iter().collect::<String>();
+                push_text(&mut rendered, before.trim_end(), format);
+                position = end;
+            } else if end >= position {
+                let before = chars[position..end].iter().collect::<String>();
+                push_text(&mut rendered, &before, format);
+                position = end;
+            }
+            push_marker(&mut rendered, number, format);
+        }
+        let rest = chars[position..].iter().collect::<String>();
+        push_text(&mut rendered, &rest, format);
+        body.push(rendered);
+    }
+
+    let mut output = body.join("\n\n");
+    if !sources.is_empty() {
+        output.push_str("\n\n");
+        push_sources(&mut output, &sources, format);
+    }
+    output
+}
+
+/// Whether the cited text is the API's inline link to `url`
+fn is_inline_link(cited: &str, url: &str) -> bool {
+    let cited = cited.trim();
+    let bare_url = url.split('?').next().unwrap_or(url);
+    (cited.starts_with('[') || cited.starts_with('(') || cited.starts_with("http"))
+        && cited.contains(bare_url)
+}
+
+/// Include the parentheses the API wraps inline links in
+fn widen_to_parentheses(chars: &[char], start: usize, end: usize, floor: usize) -> (usize, usize) {
+    if start > floor && chars.get(start - 1) == Some(&'(') && chars.get(end) == Some(&')') {
+        (start - 1, end + 1)
+    } else {
+        (start, end)
+    }
+}
+
+fn push_text(output: &mut String, text: &str, format: CitationFormat) {
+    match format {
+        CitationFormat::Html => output.push_str(&escape_html(text)),
+        CitationFormat::Markdown | CitationFormat::PlainText => output.push_str(text),
+    }
+}
+
+fn push_marker(output: &mut String, number: usize, format: CitationFormat) {
+    let _ = match format {
+        CitationFormat::Markdown => write!(output, "[^{}]", number),
+        CitationFormat::Html => write!(
+            output,
+            "<sup><a href=\"#source-{0}\">[{0}]</a></sup>",
+            number
+        ),
+        CitationFormat::PlainText => {
+            if !output.is_empty() && !output.ends_with(|c: char| c == ']' || c.is_whitespace()) {
+                output.push(' ');
+            }
+            write!(output, "[{}]", number)
+        }
+    };
+}
+
+fn push_sources(output: &mut String, sources: &[Source], format: CitationFormat) {
+    let lines: Vec<String> = sources
+        .iter()
+        .enumerate()
+        .map(|(index, source)| {
+            let number = index + 1;
+            let title = source.title.unwrap_or(source.url);
+            match format {
+                CitationFormat::Markdown if is_web_url(source.url) => format!(
+                    "[^{}]: [{}]({})",
+                    number,
+                    escape_markdown(title),
+                    escape_markdown(source.url)
+                ),
+                CitationFormat::Markdown => {
+                    format!("[^{}]: {}", number, escape_markdown(&plain_source(source)))
+                }
+                CitationFormat::Html if is_web_url(source.url) => format!(
+                    "<li id=\"source-{}\"><a href=\"{}\">{}</a></li>",
+                    number,
+                    escape_html(source.url),
+                    escape_html(title)
+                ),
+                CitationFormat::Html => format!(
+                    "<li id=\"source-{}\">{}</li>",
+                    number,
+                    escape_html(&plain_source(source))
+                ),
+                CitationFormat::PlainText => format!("[{}] {}", number, plain_source(source)),
+            }
+        })
+        .collect();
+
+    match format {
+        CitationFormat::Markdown => output.push_str(&lines.join("\n")),
+        CitationFormat::Html => {
+            let _ = write!(output, "<ol class=\"sources\">{}</ol>", lines.concat());
+        }
+        CitationFormat::PlainText => {
+            let _ = write!(output, "Sources:\n{}", lines.join("\n"));
+        }
+    }
+}
+
+/// `Title - url`, or just the URL when there is no title
+fn plain_source(source: &Source) -> String {
+    match source.title {
+        Some(title) => format!("{} - {}", title, source.url),
+        None => source.url.to_string(),
+    }
+}
+
+/// Whether `url` is safe to link to: `http` or `https`
+fn is_web_url(url: &str) -> bool {
+    ["http://", "https://"].iter().any(|scheme| {
+        url.get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    })
+}
+
+/// Backslash-escape the characters that would end a Markdown link text or destination
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '[' | ']' | '(' | ')') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,10 +1,25 @@
 //! GPT-5 API client implementation
 
+use crate::auth::{Auth, AzureConfig, Endpoint, TokenProvider};
+use crate::builder::Gpt5ClientBuilder;
+use crate::cache::ResponseCache;
+use crate::catalog::{ModelList, ModelObject};
+use crate::middleware::{Middleware, MiddlewareRequest, Next};
 use crate::models::Gpt5Model;
+use crate::options::RequestOptions;
+use crate::policy::ModelPolicy;
+use crate::redaction::{RedactionPolicy, REDACTED};
 use crate::requests::{Gpt5Request, Gpt5RequestBuilder};
 use crate::responses::{Gpt5Response, OpenAiError};
+use crate::secret::SecretString;
+use crate::streaming::ResponseStream;
+use crate::telemetry;
 use reqwest::Client;
-use std::time::Duration;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::Instrument;
 
 /// Main client for interacting with the GPT-5 API
 ///
@@ -38,15 +53,49 @@ use std::time::Duration;
 pub struct Gpt5Client {
     /// Configured HTTP client used to send requests to the OpenAI API
     pub client: Client,
-    /// Secret token used to authenticate each request with OpenAI
-    pub api_key: String,
+    /// Secret token used to authenticate each request with OpenAI (never shown in `Debug` output)
+    pub api_key: SecretString,
     /// Base URL pointing at the OpenAI API (override for proxies/self-hosted gateways)
     pub base_url: String,
+    /// Organization ID sent as the `OpenAI-Organization` header on every request
+    pub organization: Option<String>,
+    /// Project ID sent as the `OpenAI-Project` header on every request
+    pub project: Option<String>,
+    /// Authentication strategy (OpenAI bearer key, Azure `api-key`, or token provider)
+    pub auth: Auth,
+    /// URL strategy used to turn `base_url` into endpoint URLs
+    pub endpoint: Endpoint,
+    /// Middleware layers wrapping every call to `request`, outermost first
+    middleware: Vec<Arc<dyn Middleware>>,
+    /// Policy applied to request/response text before it is logged
+    redaction: RedactionPolicy,
+    /// Models this client will send requests for
+    model_policy: ModelPolicy,
+}
+
+impl fmt::Debug for Gpt5Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gpt5Client")
+            .field("api_key", &self.api_key)
+            .field("base_url", &self.base_url)
+            .field("organization", &self.organization)
+            .field("project", &self.project)
+            .field("auth", &self.auth)
+            .field("endpoint", &self.endpoint)
+            .field("middleware", &self.middleware.len())
+            .field("redaction", &self.redaction)
+            .field("model_policy", &self.model_policy)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Gpt5Client {
     /// Create a new GPT-5 client with the specified API key
     ///
+    /// Uses a 60 second request timeout. If the HTTP client cannot be built
+    /// a default one is used instead; use [`Gpt5Client::builder`] to tune the
+    /// transport and get an error instead.
+    ///
     /// # Arguments
     ///
     /// * `api_key` - Your OpenAI API key
@@ -59,30 +108,187 @@ impl Gpt5Client {
     /// let client = Gpt5Client::new("sk-...".to_string());
     /// ```
     pub fn new(api_key: String) -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(60))
+        let api_key = SecretString::new(api_key);
+        Gpt5ClientBuilder::new(api_key.clone())
             .build()
-            .map_err(|error| {
+            .unwrap_or_else(|error| {
                 tracing::warn!(
                     "Failed to build reqwest client with timeout, falling back to default: {}",
                     error
                 );
-                error
+                Self::from_http_client(Client::new(), api_key)
             })
-            .unwrap_or_else(|_| Client::new());
+    }
 
+    /// Start a [`Gpt5ClientBuilder`] for connection pooling, timeouts, proxies and TLS
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Gpt5Client::builder("sk-...")
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .proxy("http://proxy.internal:3128")
+    ///     .build()
+    ///     .expect("valid client configuration");
+    /// ```
+    pub fn builder(api_key: impl Into<SecretString>) -> Gpt5ClientBuilder {
+        Gpt5ClientBuilder::new(api_key)
+    }
+
+    /// Wrap an already configured HTTP client
+    pub(crate) fn from_http_client(client: Client, api_key: SecretString) -> Self {
         Self {
             client,
             api_key,
             base_url: "https://api.openai.com".to_string(),
+            organization: None,
+            project: None,
+            auth: Auth::default(),
+            endpoint: Endpoint::default(),
+            middleware: Vec::new(),
+            redaction: RedactionPolicy::default(),
+            model_policy: ModelPolicy::default(),
+        }
+    }
+
+    /// Create a client configured from environment variables
+    ///
+    /// | Variable | Purpose |
+    /// |----------|---------|
+    /// | `OPENAI_API_KEY` | API key (required) |
+    /// | `OPENAI_BASE_URL` | Base URL; a trailing `/v1` is accepted and stripped |
+    /// | `OPENAI_ORG_ID` | Value for the `OpenAI-Organization` header |
+    /// | `OPENAI_PROJECT_ID` | Value for the `OpenAI-Project` header |
+    ///
+    /// Each variable may instead be supplied as `<NAME>_FILE` pointing at a
+    /// file whose (trimmed) contents hold the value, which suits secrets
+    /// mounted by Docker or Kubernetes. The plain variable wins when both
+    /// are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use gpt5::Gpt5Client;
+    ///
+    /// let client = Gpt5Client::from_env().expect("OPENAI_API_KEY must be set");
+    /// ```
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Build a client from an arbitrary variable source (see `from_env`)
+    pub(crate) fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let read = |name: &str| -> anyhow::Result<Option<String>> {
+            if let Some(value) = lookup(name).filter(|value| !value.trim().is_empty()) {
+                return Ok(Some(value.trim().to_string()));
+            }
+
+            let file_var = format!("{}_FILE", name);
+            match lookup(&file_var).filter(|path| !path.trim().is_empty()) {
+                Some(path) => {
+                    let contents = std::fs::read_to_string(path.trim()).map_err(|error| {
+                        anyhow::anyhow!("Failed to read {} ({}): {}", file_var, path, error)
+                    })?;
+                    Ok(Some(contents.trim().to_string()).filter(|value| !value.is_empty()))
+                }
+                None => Ok(None),
+            }
+        };
+
+        let api_key = read("OPENAI_API_KEY")?
+            .ok_or_else(|| anyhow::anyhow!("OPENAI_API_KEY (or OPENAI_API_KEY_FILE) is not set"))?;
+
+        let mut client = Self::new(api_key);
+        if let Some(base_url) = read("OPENAI_BASE_URL")? {
+            let base_url = base_url.trim_end_matches('/');
+            client.base_url = base_url.strip_suffix("/v1").unwrap_or(base_url).to_string();
         }
+        client.organization = read("OPENAI_ORG_ID")?;
+        client.project = read("OPENAI_PROJECT_ID")?;
+
+        Ok(client)
+    }
+
+    /// Create a client for an Azure OpenAI resource using `api-key` authentication
+    ///
+    /// Azure deployments can have any name (`my-gpt5-prod`), so the client
+    /// starts with [`ModelPolicy::Disabled`]; use
+    /// [`with_model_policy`](Self::with_model_policy) to check model names.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Resource endpoint, e.g. `https://my-resource.openai.azure.com`
+    /// * `config` - Deployment and `api-version` routing
+    /// * `api_key` - Azure OpenAI key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{AzureConfig, Gpt5Client};
+    ///
+    /// let client = Gpt5Client::azure(
+    ///     "https://my-resource.openai.azure.com".to_string(),
+    ///     AzureConfig::deployment("gpt-5", "2025-04-01-preview"),
+    ///     "azure-key".to_string(),
+    /// );
+    /// ```
+    pub fn azure(endpoint: String, config: AzureConfig, api_key: String) -> Self {
+        Self::new(api_key)
+            .with_base_url(endpoint)
+            .with_endpoint(Endpoint::Azure(config))
+            .with_auth(Auth::AzureApiKey)
+            .with_model_policy(ModelPolicy::Disabled)
+    }
+
+    /// Create a client for an Azure OpenAI resource using Entra ID bearer tokens
+    ///
+    /// The provider is asked for a token before every request. As with
+    /// [`azure`](Self::azure), model names are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{AzureConfig, Gpt5Client};
+    ///
+    /// let client = Gpt5Client::azure_with_token_provider(
+    ///     "https://my-resource.openai.azure.com".to_string(),
+    ///     AzureConfig::deployment("gpt-5", "2025-04-01-preview"),
+    ///     || async { Ok("entra-access-token".to_string()) },
+    /// );
+    /// ```
+    pub fn azure_with_token_provider(
+        endpoint: String,
+        config: AzureConfig,
+        provider: impl TokenProvider + 'static,
+    ) -> Self {
+        Self::new(String::new())
+            .with_base_url(endpoint)
+            .with_endpoint(Endpoint::Azure(config))
+            .with_auth(Auth::TokenProvider(Arc::new(provider)))
+            .with_model_policy(ModelPolicy::Disabled)
+    }
+
+    /// Replace the authentication strategy
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Replace the URL strategy
+    pub fn with_endpoint(mut self, endpoint: Endpoint) -> Self {
+        self.endpoint = endpoint;
+        self
     }
 
     /// Replace the underlying HTTP client.
     ///
     /// This allows callers to configure advanced settings like proxies,
     /// retries, or custom TLS behaviour while still using the high level
-    /// `Gpt5Client` interface.
+    /// `Gpt5Client` interface. [`Gpt5Client::builder`] covers the common
+    /// settings without constructing a `reqwest::Client` by hand.
     pub fn with_http_client(mut self, client: Client) -> Self {
         self.client = client;
         self
@@ -107,6 +313,103 @@ impl Gpt5Client {
         self
     }
 
+    /// Send an `OpenAI-Organization` header with every request
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Client;
+    ///
+    /// let client = Gpt5Client::new("sk-...".to_string())
+    ///     .with_organization("org-123".to_string())
+    ///     .with_project("proj_abc".to_string());
+    /// ```
+    pub fn with_organization(mut self, organization: String) -> Self {
+        self.organization = Some(organization);
+        self
+    }
+
+    /// Send an `OpenAI-Project` header with every request
+    pub fn with_project(mut self, project: String) -> Self {
+        self.project = Some(project);
+        self
+    }
+
+    /// Append a middleware layer to the request pipeline
+    ///
+    /// Layers run in the order they are added: the first layer sees the
+    /// request first and the response last. See the [`middleware`](crate::middleware)
+    /// module for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::middleware::HeadersMiddleware;
+    /// use gpt5::Gpt5Client;
+    ///
+    /// let client = Gpt5Client::new("sk-...".to_string())
+    ///     .with_middleware(HeadersMiddleware::new().header("x-request-source", "docs").unwrap());
+    /// ```
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Scrub logged request/response text with a redaction policy
+    ///
+    /// The policy applies to every body and error message this client emits
+    /// through `tracing`. The API key itself is always masked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Client, RedactionPolicy};
+    ///
+    /// let client = Gpt5Client::new("sk-...".to_string())
+    ///     .with_redaction(RedactionPolicy::new().pattern(r"\d{4}-\d{4}-\d{4}-\d{4}").unwrap());
+    /// ```
+    pub fn with_redaction(mut self, policy: RedactionPolicy) -> Self {
+        self.redaction = policy;
+        self
+    }
+
+    /// Choose which models requests may use
+    ///
+    /// The default, [`ModelPolicy::Gpt5Only`], rejects any model not named
+    /// `gpt-5*` before a request is sent. Use an allowlist or predicate for
+    /// fine-tuned ids and gateway aliases, or [`ModelPolicy::Disabled`] to
+    /// skip the check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Client, ModelPolicy};
+    ///
+    /// let client = Gpt5Client::new("sk-...".to_string())
+    ///     .with_model_policy(ModelPolicy::allowlist(["gpt-5*", "ft:gpt-5-mini:acme:*"]));
+    /// ```
+    pub fn with_model_policy(mut self, policy: ModelPolicy) -> Self {
+        self.model_policy = policy;
+        self
+    }
+
+    /// Cache responses to identical requests
+    ///
+    /// The cache is added as a middleware layer at the current position in
+    /// the chain. Keep a clone of the cache to read its hit/miss counters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Client, ResponseCache};
+    ///
+    /// let cache = ResponseCache::on_disk(".gpt5-cache");
+    /// let client = Gpt5Client::new("sk-...".to_string()).with_cache(cache.clone());
+    /// ```
+    pub fn with_cache(self, cache: ResponseCache) -> Self {
+        self.with_middleware(cache)
+    }
+
     /// Send a request to the GPT-5 API
     ///
     /// # Arguments
@@ -135,69 +438,279 @@ impl Gpt5Client {
     /// }
     /// ```
     pub async fn request(&self, req: Gpt5Request) -> anyhow::Result<Gpt5Response> {
-        // Validate GPT-5 model
-        if !self.is_gpt5_model(&req.model) {
-            return Err(anyhow::anyhow!(
-                "Only GPT-5 models are supported. Got: {}",
-                req.model
-            ));
+        self.request_with_options(req, RequestOptions::default())
+            .await
+    }
+
+    /// Send a request with per-call overrides
+    ///
+    /// The options apply to this call only: a longer timeout for a slow
+    /// reasoning request, extra headers, an `Idempotency-Key`, or a
+    /// different API key. Middleware sees the overrides on
+    /// [`MiddlewareRequest`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder, RequestOptions};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Gpt5Client::new("sk-...".to_string());
+    ///     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///         .input("Prove that there are infinitely many primes")
+    ///         .build();
+    ///
+    ///     let options = RequestOptions::new()
+    ///         .timeout(Duration::from_secs(600))
+    ///         .idempotency_key("primes-proof-1");
+    ///     let response = client.request_with_options(request, options).await?;
+    ///     println!("{:?}", response.text());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn request_with_options(
+        &self,
+        req: Gpt5Request,
+        options: RequestOptions,
+    ) -> anyhow::Result<Gpt5Response> {
+        let span = telemetry::request_span(&req);
+        let started = Instant::now();
+
+        let result = async {
+            self.check_model(&req.model)?;
+
+            let req = MiddlewareRequest {
+                request: req,
+                headers: options.header_map()?,
+                timeout: options.timeout,
+                api_key: options.api_key,
+            };
+
+            Next::new(self, &self.middleware).run(req).await
         }
+        .instrument(span.clone())
+        .await;
 
-        let url = format!("{}/v1/responses", self.base_url);
+        telemetry::record_latency(&span, started.elapsed());
+        match &result {
+            Ok(response) => telemetry::record_response(&span, response),
+            Err(error) => telemetry::record_error(&span, error),
+        }
 
-        let response = self
+        result
+    }
+
+    /// Send a request and stream the response as server-sent events
+    ///
+    /// Sets `stream: true` on the request. Streaming calls go straight to
+    /// the API: middleware (including the response cache) is not applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder, StreamEvent};
+    /// use std::io::Write;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Gpt5Client::new("sk-...".to_string());
+    ///     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///         .input("Write a haiku about Rust")
+    ///         .build();
+    ///
+    ///     let mut stream = client.request_stream(request).await?;
+    ///     while let Some(event) = stream.next().await {
+    ///         if let StreamEvent::OutputTextDelta { delta, .. } = event? {
+    ///             print!("{}", delta);
+    ///             std::io::stdout().flush()?;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn request_stream(&self, req: Gpt5Request) -> anyhow::Result<ResponseStream> {
+        self.request_stream_with_options(req, RequestOptions::default())
+            .await
+    }
+
+    /// Stream a response with per-call overrides
+    ///
+    /// The timeout covers the whole stream, not just the time to the first
+    /// event, so set it generously for long generations.
+    pub async fn request_stream_with_options(
+        &self,
+        mut req: Gpt5Request,
+        options: RequestOptions,
+    ) -> anyhow::Result<ResponseStream> {
+        let span = telemetry::request_span(&req);
+        let started = Instant::now();
+
+        let result = async {
+            self.check_model(&req.model)?;
+            req.parameters
+                .insert("stream".to_string(), serde_json::Value::Bool(true));
+
+            let req = MiddlewareRequest {
+                request: req,
+                headers: options.header_map()?,
+                timeout: options.timeout,
+                api_key: options.api_key,
+            };
+
+            let response = self.dispatch(&req).await?;
+            Ok(ResponseStream::new(response))
+        }
+        .instrument(span.clone())
+        .await;
+
+        // Latency covers the time until the stream was established
+        telemetry::record_latency(&span, started.elapsed());
+        if let Err(error) = &result {
+            telemetry::record_error(&span, error);
+        }
+
+        result
+    }
+
+    /// Perform the HTTP call at the end of the middleware chain
+    pub(crate) async fn send(&self, req: MiddlewareRequest) -> anyhow::Result<Gpt5Response> {
+        let response = self.dispatch(&req).await?;
+        let response_text = response.text().await?;
+
+        // Bodies may contain user data, so they are only logged at TRACE level
+        tracing::trace!(
+            "GPT-5 response body: {}",
+            self.redact_with(&response_text, req.api_key.as_ref())
+        );
+
+        // Serde errors quote the offending value
+        serde_json::from_str::<Gpt5Response>(&response_text).map_err(|parse_error| {
+            let parse_error = self
+                .redact_with(&parse_error.to_string(), req.api_key.as_ref())
+                .into_owned();
+            tracing::error!("Failed to parse GPT-5 response: {}", parse_error);
+            anyhow::anyhow!("Failed to parse GPT-5 response: {}", parse_error)
+        })
+    }
+
+    /// Post a request to `/responses`, turning non-success statuses into errors
+    async fn dispatch(&self, req: &MiddlewareRequest) -> anyhow::Result<reqwest::Response> {
+        let url = self.endpoint.url(&self.base_url, "responses");
+
+        if tracing::enabled!(tracing::Level::TRACE) {
+            let body = serde_json::to_string(&req.request).unwrap_or_default();
+            tracing::trace!("GPT-5 request body: {}", self.redact(&body));
+        }
+
+        let mut builder = self
             .client
             .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
-            .json(&req)
+            .header("Content-Type", "application/json");
+        if let Some(timeout) = req.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = self
+            .authorize(builder, req.api_key.as_ref())
+            .await?
+            .headers(req.headers.clone())
+            .json(&req.request)
             .send()
             .await?;
 
-        let status = response.status();
-        let response_text = response.text().await?;
-
-        // Log the raw response for debugging
-        tracing::info!("GPT-5 raw response: {}", response_text);
+        tracing::Span::current().record("http.response.status_code", response.status().as_u16());
+        self.error_for_status(response, req.api_key.as_ref()).await
+    }
 
-        let json_value =
-            serde_json::from_str::<serde_json::Value>(&response_text).map_err(|json_error| {
-                tracing::error!("Invalid JSON response from GPT-5: {}", json_error);
-                tracing::error!("Raw response: {}", response_text);
-                anyhow::anyhow!("Invalid JSON response: {}", json_error)
-            })?;
+    /// Pass successful responses through and turn failures into `ApiError`s
+    async fn error_for_status(
+        &self,
+        response: reqwest::Response,
+        api_key: Option<&SecretString>,
+    ) -> anyhow::Result<reqwest::Response> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
 
-        tracing::info!("GPT-5 response JSON structure: {:#}", json_value);
+        // Parse before redacting: a mask could break the JSON
+        let response_text = response.text().await?;
+        let redacted_text = self.redact_with(&response_text, api_key);
+        tracing::trace!("GPT-5 response body: {}", redacted_text);
 
-        if !status.is_success() {
-            if let Ok(error_response) = serde_json::from_value::<OpenAiError>(json_value.clone()) {
-                tracing::error!(
-                    "OpenAI API error (status {}): {}",
-                    status,
-                    error_response.error.message
-                );
-                return Err(anyhow::anyhow!(
-                    "OpenAI API error (status {}): {}",
-                    status,
-                    error_response.error.message
-                ));
+        if let Ok(error_response) = serde_json::from_str::<OpenAiError>(&response_text) {
+            let message = self
+                .redact_with(&error_response.error.message, api_key)
+                .into_owned();
+            tracing::error!("OpenAI API error (status {}): {}", status, message);
+            return Err(ApiError {
+                status: status.as_u16(),
+                kind: Some(error_response.error.error_type),
+                message: format!("OpenAI API error (status {}): {}", status, message),
             }
+            .into());
+        }
 
-            tracing::error!("OpenAI API request failed with status {}", status);
-            return Err(anyhow::anyhow!(
+        tracing::error!("OpenAI API request failed with status {}", status);
+        Err(ApiError {
+            status: status.as_u16(),
+            kind: None,
+            message: format!(
                 "OpenAI API request failed with status {}: {}",
-                status,
-                response_text
-            ));
+                status, redacted_text
+            ),
         }
+        .into())
+    }
 
-        serde_json::from_value::<Gpt5Response>(json_value).map_err(|parse_error| {
-            tracing::error!("Failed to parse GPT-5 response: {}", parse_error);
-            tracing::error!("Raw response: {}", response_text);
-            anyhow::anyhow!("Failed to parse GPT-5 response: {}", parse_error)
+    /// Send an authorized `GET` and parse the JSON body
+    pub(crate) async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> anyhow::Result<T> {
+        self.send_json(reqwest::Method::GET, path, None).await
+    }
+
+    /// Send an authorized call with an optional JSON body and parse the JSON reply
+    pub(crate) async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> anyhow::Result<T> {
+        let url = self.endpoint.url(&self.base_url, path);
+        let mut builder = self
+            .authorize(self.client.request(method, &url), None)
+            .await?;
+        if let Some(body) = body {
+            tracing::trace!("GPT-5 request body: {}", self.redact(&body.to_string()));
+            builder = builder.json(body);
+        }
+        let response = builder.send().await?;
+        let response = self.error_for_status(response, None).await?;
+
+        let response_text = response.text().await?;
+        tracing::trace!("GPT-5 response body: {}", self.redact(&response_text));
+        serde_json::from_str(&response_text).map_err(|error| {
+            let error = self.redact(&error.to_string()).into_owned();
+            anyhow::anyhow!("Failed to parse {} response: {}", path, error)
         })
     }
 
+    /// Send an authorized `GET` and return the raw body, e.g. a file download
+    pub(crate) async fn get_bytes(&self, path: &str) -> anyhow::Result<Vec<u8>> {
+        let url = self.endpoint.url(&self.base_url, path);
+        let response = self
+            .authorize(self.client.get(&url), None)
+            .await?
+            .send()
+            .await?;
+        let response = self.error_for_status(response, None).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
     /// Send a simple request and get text response
     ///
     /// # Arguments
@@ -260,16 +773,158 @@ impl Gpt5Client {
         Err(anyhow::anyhow!("No text content in response output"))
     }
 
-    /// Validate if a model is a GPT-5 model
+    /// Attach authentication and account headers to an outgoing request
+    ///
+    /// An override key is sent as a bearer token even when the client uses a
+    /// token provider, and as the `api-key` header for Azure key auth.
+    pub(crate) async fn authorize(
+        &self,
+        mut builder: reqwest::RequestBuilder,
+        api_key: Option<&SecretString>,
+    ) -> anyhow::Result<reqwest::RequestBuilder> {
+        let key = api_key.unwrap_or(&self.api_key);
+        builder = match (&self.auth, api_key) {
+            (Auth::AzureApiKey, _) => builder.header("api-key", key.expose_secret()),
+            (Auth::TokenProvider(provider), None) => {
+                let token = provider.token().await?;
+                builder.header("Authorization", format!("Bearer {}", token))
+            }
+            _ => builder.header("Authorization", format!("Bearer {}", key.expose_secret())),
+        };
+
+        if let Some(organization) = &self.organization {
+            builder = builder.header("OpenAI-Organization", organization);
+        }
+        if let Some(project) = &self.project {
+            builder = builder.header("OpenAI-Project", project);
+        }
+
+        Ok(builder)
+    }
+
+    /// Apply the redaction policy and mask the API key
+    pub(crate) fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        self.redact_with(text, None)
+    }
+
+    /// Like `redact`, also masking a per-request API key
+    fn redact_with<'a>(&self, text: &'a str, api_key: Option<&SecretString>) -> Cow<'a, str> {
+        let mut text = self.redaction.redact(text);
+
+        for key in std::iter::once(&self.api_key).chain(api_key) {
+            let key = key.expose_secret();
+            if !key.is_empty() && text.contains(key) {
+                text = Cow::Owned(text.replace(key, REDACTED));
+            }
+        }
+
+        text
+    }
+
+    /// List the models available to this API key
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `model` - The model string to validate
+    /// ```rust,no_run
+    /// use gpt5::Gpt5Client;
     ///
-    /// # Returns
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Gpt5Client::from_env()?;
+    ///     for model in client.list_models().await? {
+    ///         if model.info().is_some() {
+    ///             println!("{}", model.id);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_models(&self) -> anyhow::Result<Vec<ModelObject>> {
+        let list: ModelList = self.get_json("models").await?;
+        Ok(list.data)
+    }
+
+    /// Fetch a single model by name
+    pub async fn retrieve_model(&self, id: &str) -> anyhow::Result<ModelObject> {
+        self.get_json(&format!("models/{}", encode_component(id)))
+            .await
+    }
+
+    /// Confirm `model` exists for this API key, e.g. at startup
+    ///
+    /// Returns an error naming the model when the API reports it missing
+    /// (HTTP 404); other failures are passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use gpt5::{Gpt5Client, Gpt5Model};
     ///
-    /// * `bool` - True if it's a GPT-5 model, false otherwise
-    fn is_gpt5_model(&self, model: &str) -> bool {
-        matches!(model, "gpt-5" | "gpt-5-mini" | "gpt-5-nano") || model.starts_with("gpt-5")
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Gpt5Client::from_env()?;
+    ///     let model = Gpt5Model::Custom("gpt-5-mini-2025-08-07".to_string());
+    ///     client.ensure_model_available(&model).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn ensure_model_available(&self, model: &Gpt5Model) -> anyhow::Result<ModelObject> {
+        self.retrieve_model(model.as_str()).await.map_err(|error| {
+            match error.downcast_ref::<ApiError>() {
+                Some(api_error) if api_error.status == 404 => anyhow::anyhow!(
+                    "Model {} is not available for this API key: {}",
+                    model.as_str(),
+                    api_error.message
+                ),
+                _ => error,
+            }
+        })
+    }
+
+    /// Reject models the client's policy does not allow
+    fn check_model(&self, model: &str) -> anyhow::Result<()> {
+        self.model_policy.check(model)
+    }
+}
+
+/// Non-success HTTP status returned by the API
+#[derive(Debug)]
+pub(crate) struct ApiError {
+    /// HTTP status code of the failed call
+    pub(crate) status: u16,
+    /// OpenAI error type from the error body, if one could be parsed
+    pub(crate) kind: Option<String>,
+    /// Human-readable message surfaced to callers
+    pub(crate) message: String,
+}
+
+impl ApiError {
+    /// Value for the `error.type` span attribute
+    pub(crate) fn error_type(&self) -> String {
+        self.kind.clone().unwrap_or_else(|| self.status.to_string())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Percent-encode `value` for use as a single URL path segment or query value
+///
+/// Everything except RFC 3986 unreserved characters is escaped, so ids
+/// containing `/`, `?`, `#` or `:` stay within one segment.
+pub(crate) fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
     }
+    encoded
 }
@@ -0,0 +1,118 @@
+//! Files created by the hosted `code_interpreter` tool (`/v1/containers`)
+//!
+//! Code interpreter runs in a container; charts, CSVs and other files the
+//! code writes stay there until they are downloaded.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder};
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let client = Gpt5Client::from_env()?;
+//!     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+//!         .input("Chart monthly revenue from sales.csv and save it as revenue.png")
+//!         .code_interpreter_files(["file-sales"])
+//!         .build();
+//!     let response = client.request(request).await?;
+//!
+//!     for path in client.download_container_files(&response, "charts").await? {
+//!         println!("saved {}", path.display());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::client::{encode_component, Gpt5Client};
+use crate::responses::Gpt5Response;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A file stored in a code interpreter container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerFile {
+    /// Container holding the file
+    pub container_id: String,
+    /// Identifier of the file within its container
+    pub file_id: String,
+    /// Name of the file, when the response mentions one
+    pub filename: Option<String>,
+}
+
+impl ContainerFile {
+    /// Name to save the file under: its file name without any directories, or its id
+    pub fn local_name(&self) -> String {
+        self.filename
+            .as_deref()
+            .and_then(file_name)
+            .or_else(|| file_name(&self.file_id))
+            .unwrap_or("file")
+            .to_string()
+    }
+}
+
+/// Last component of `name`, or `None` for `..`, `/` and the like
+fn file_name(name: &str) -> Option<&str> {
+    Path::new(name).file_name()?.to_str()
+}
+
+impl Gpt5Client {
+    /// Download the contents of a container file
+    pub async fn container_file_content(
+        &self,
+        container_id: &str,
+        file_id: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        self.get_bytes(&format!(
+            "containers/{}/files/{}/content",
+            encode_component(container_id),
+            encode_component(file_id)
+        ))
+        .await
+    }
+
+    /// Save every container file a response refers to into `dir`
+    ///
+    /// Creates `dir` if needed and returns the written paths in the order of
+    /// [`Gpt5Response::container_files`]. Files are named after
+    /// [`ContainerFile::local_name`]; when two files share a name the later
+    /// one is prefixed with its file id, plus a counter if that is taken too.
+    /// Existing files are overwritten.
+    pub async fn download_container_files(
+        &self,
+        response: &Gpt5Response,
+        dir: impl AsRef<Path>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|error| {
+            anyhow::anyhow!("Failed to create directory {}: {}", dir.display(), error)
+        })?;
+
+        let mut names = HashSet::new();
+        let mut paths = Vec::new();
+        for file in response.container_files() {
+            let contents = self
+                .container_file_content(&file.container_id, &file.file_id)
+                .await?;
+
+            let base = file.local_name();
+            let prefix = file_name(&file.file_id).unwrap_or("file");
+            let mut name = base.clone();
+            let mut attempt = 1;
+            while !names.insert(name.clone()) {
+                name = match attempt {
+                    1 => format!("{}-{}", prefix, base),
+                    _ => format!("{}-{}-{}", prefix, attempt, base),
+                };
+                attempt += 1;
+            }
+            let path = dir.join(name);
+            std::fs::write(&path, contents).map_err(|error| {
+                anyhow::anyhow!("Failed to write {}: {}", path.display(), error)
+            })?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
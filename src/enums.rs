@@ -16,6 +16,8 @@
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReasoningEffort {
+    /// Minimal reasoning effort - few or no reasoning tokens
+    Minimal,
     /// Low reasoning effort - fastest responses
     Low,
     /// Medium reasoning effort - balanced performance
@@ -32,6 +34,7 @@ impl serde::Serialize for ReasoningEffort {
         S: serde::Serializer,
     {
         match self {
+            ReasoningEffort::Minimal => serializer.serialize_str("minimal"),
             ReasoningEffort::Low => serializer.serialize_str("low"),
             ReasoningEffort::Medium => serializer.serialize_str("medium"),
             ReasoningEffort::High => serializer.serialize_str("high"),
@@ -47,6 +50,7 @@ impl<'de> serde::Deserialize<'de> for ReasoningEffort {
     {
         let s = String::deserialize(deserializer)?;
         Ok(match s.as_str() {
+            "minimal" => ReasoningEffort::Minimal,
             "low" => ReasoningEffort::Low,
             "medium" => ReasoningEffort::Medium,
             "high" => ReasoningEffort::High,
@@ -127,6 +131,12 @@ pub enum OutputType {
     Message,
     /// Function call output - tool/function invocation
     FunctionCall,
+    /// Web search run by the hosted `web_search` tool
+    WebSearchCall,
+    /// Vector store search run by the hosted `file_search` tool
+    FileSearchCall,
+    /// Python code run by the hosted `code_interpreter` tool
+    CodeInterpreterCall,
     /// Unknown output type (for future compatibility)
     Unknown(String),
 }
@@ -139,6 +149,9 @@ impl serde::Serialize for OutputType {
         match self {
             OutputType::Message => serializer.serialize_str("message"),
             OutputType::FunctionCall => serializer.serialize_str("function_call"),
+            OutputType::WebSearchCall => serializer.serialize_str("web_search_call"),
+            OutputType::FileSearchCall => serializer.serialize_str("file_search_call"),
+            OutputType::CodeInterpreterCall => serializer.serialize_str("code_interpreter_call"),
             OutputType::Unknown(s) => serializer.serialize_str(s),
         }
     }
@@ -153,6 +166,9 @@ impl<'de> serde::Deserialize<'de> for OutputType {
         Ok(match s.as_str() {
             "message" => OutputType::Message,
             "function_call" => OutputType::FunctionCall,
+            "web_search_call" => OutputType::WebSearchCall,
+            "file_search_call" => OutputType::FileSearchCall,
+            "code_interpreter_call" => OutputType::CodeInterpreterCall,
             _ => OutputType::Unknown(s),
         })
     }
@@ -364,3 +380,481 @@ impl<'de> serde::Deserialize<'de> for FormatType {
         })
     }
 }
+
+/// Truncation strategy for inputs that exceed the context window
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::Truncation;
+///
+/// let auto = Truncation::Auto;         // Drop items from the middle of the conversation
+/// let disabled = Truncation::Disabled; // Fail with a 400 error instead
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Truncation {
+    /// Drop input items from the middle of the conversation to fit
+    Auto,
+    /// Reject requests that do not fit (the API default)
+    Disabled,
+    /// Unknown truncation strategy (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for Truncation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Truncation::Auto => serializer.serialize_str("auto"),
+            Truncation::Disabled => serializer.serialize_str("disabled"),
+            Truncation::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Truncation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "auto" => Truncation::Auto,
+            "disabled" => Truncation::Disabled,
+            _ => Truncation::Unknown(s),
+        })
+    }
+}
+
+/// Processing tier used to serve a request
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::ServiceTier;
+///
+/// let flex = ServiceTier::Flex;         // Cheaper, slower processing
+/// let priority = ServiceTier::Priority; // Faster, more expensive processing
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceTier {
+    /// Use the tier configured for the project
+    Auto,
+    /// Standard pricing and performance
+    Default,
+    /// Lower price with higher latency and occasional unavailability
+    Flex,
+    /// Faster processing at a higher price
+    Priority,
+    /// Scale Tier capacity, where purchased
+    Scale,
+    /// Unknown service tier (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for ServiceTier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            ServiceTier::Auto => serializer.serialize_str("auto"),
+            ServiceTier::Default => serializer.serialize_str("default"),
+            ServiceTier::Flex => serializer.serialize_str("flex"),
+            ServiceTier::Priority => serializer.serialize_str("priority"),
+            ServiceTier::Scale => serializer.serialize_str("scale"),
+            ServiceTier::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ServiceTier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "auto" => ServiceTier::Auto,
+            "default" => ServiceTier::Default,
+            "flex" => ServiceTier::Flex,
+            "priority" => ServiceTier::Priority,
+            "scale" => ServiceTier::Scale,
+            _ => ServiceTier::Unknown(s),
+        })
+    }
+}
+
+/// Which tools the model may or must call
+///
+/// Serializes to the string (`"auto"`, `"none"`, `"required"`) or object
+/// form the Responses API expects. String arguments convert with `From`, so
+/// `.tool_choice("auto")` keeps working.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::{AllowedToolsMode, ToolChoice};
+/// use serde_json::json;
+///
+/// let forced = ToolChoice::Function("get_weather".to_string());
+/// assert_eq!(
+///     serde_json::to_value(&forced).unwrap(),
+///     json!({"type": "function", "name": "get_weather"})
+/// );
+///
+/// let subset = ToolChoice::AllowedTools {
+///     mode: AllowedToolsMode::Auto,
+///     tools: vec![
+///         ToolChoice::Function("get_weather".to_string()),
+///         ToolChoice::Hosted("web_search".to_string()),
+///     ],
+/// };
+/// assert_eq!(
+///     serde_json::to_value(&subset).unwrap(),
+///     json!({
+///         "type": "allowed_tools",
+///         "mode": "auto",
+///         "tools": [{"type": "function", "name": "get_weather"}, {"type": "web_search"}]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolChoice {
+    /// The model decides whether to call tools
+    Auto,
+    /// The model must not call tools
+    None,
+    /// The model must call at least one tool
+    Required,
+    /// The model must call the named function
+    Function(String),
+    /// The model may only use these tools (`Function` or `Hosted` entries)
+    AllowedTools {
+        /// Whether the listed tools are optional or required
+        mode: AllowedToolsMode,
+        /// Tools the model may choose from
+        tools: Vec<ToolChoice>,
+    },
+    /// The model must use a built-in tool, e.g. `web_search` or `file_search`
+    Hosted(String),
+    /// Unknown tool choice (for future compatibility)
+    Unknown(serde_json::Value),
+}
+
+impl From<&str> for ToolChoice {
+    fn from(choice: &str) -> Self {
+        match choice {
+            "auto" => ToolChoice::Auto,
+            "none" => ToolChoice::None,
+            "required" => ToolChoice::Required,
+            other => ToolChoice::Unknown(serde_json::Value::String(other.to_string())),
+        }
+    }
+}
+
+impl serde::Serialize for ToolChoice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        match self {
+            ToolChoice::Auto => serializer.serialize_str("auto"),
+            ToolChoice::None => serializer.serialize_str("none"),
+            ToolChoice::Required => serializer.serialize_str("required"),
+            ToolChoice::Function(name) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("type", "function")?;
+                map.serialize_entry("name", name)?;
+                map.end()
+            }
+            ToolChoice::AllowedTools { mode, tools } => {
+                let mut map = serializer.serialize_map(Some(3))?;
+                map.serialize_entry("type", "allowed_tools")?;
+                map.serialize_entry("mode", mode)?;
+                map.serialize_entry("tools", tools)?;
+                map.end()
+            }
+            ToolChoice::Hosted(tool_type) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("type", tool_type)?;
+                map.end()
+            }
+            ToolChoice::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ToolChoice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde_json::Value;
+
+        let value = Value::deserialize(deserializer)?;
+        let object = match &value {
+            Value::String(choice) => return Ok(ToolChoice::from(choice.as_str())),
+            Value::Object(object) => object,
+            _ => return Ok(ToolChoice::Unknown(value)),
+        };
+
+        let field = |name: &str| object.get(name).and_then(Value::as_str);
+        let allowed_tools = || {
+            Some(ToolChoice::AllowedTools {
+                mode: serde_json::from_value(object.get("mode")?.clone()).ok()?,
+                tools: serde_json::from_value(object.get("tools")?.clone()).ok()?,
+            })
+        };
+        let choice = match (field("type"), field("name"), object.len()) {
+            (Some("function"), Some(name), 2) => Some(ToolChoice::Function(name.to_string())),
+            (Some("allowed_tools"), _, _) => allowed_tools(),
+            (Some(tool_type), None, 1) => Some(ToolChoice::Hosted(tool_type.to_string())),
+            _ => None,
+        };
+        Ok(choice.unwrap_or(ToolChoice::Unknown(value)))
+    }
+}
+
+/// Whether the tools listed in `ToolChoice::AllowedTools` are optional
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllowedToolsMode {
+    /// The model may call one of the tools or none
+    Auto,
+    /// The model must call one of the tools
+    Required,
+    /// Unknown mode (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for AllowedToolsMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            AllowedToolsMode::Auto => serializer.serialize_str("auto"),
+            AllowedToolsMode::Required => serializer.serialize_str("required"),
+            AllowedToolsMode::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AllowedToolsMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "auto" => AllowedToolsMode::Auto,
+            "required" => AllowedToolsMode::Required,
+            _ => AllowedToolsMode::Unknown(s),
+        })
+    }
+}
+
+/// Amount of retrieved web content placed in the model's context
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::SearchContextSize;
+///
+/// let cheap = SearchContextSize::Low;   // Faster, cheaper, less context
+/// let rich = SearchContextSize::High;   // Slower, more thorough answers
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchContextSize {
+    /// Least context - fastest and cheapest
+    Low,
+    /// Balanced context (the API default)
+    Medium,
+    /// Most context - most comprehensive answers
+    High,
+    /// Unknown context size (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for SearchContextSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            SearchContextSize::Low => serializer.serialize_str("low"),
+            SearchContextSize::Medium => serializer.serialize_str("medium"),
+            SearchContextSize::High => serializer.serialize_str("high"),
+            SearchContextSize::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SearchContextSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "low" => SearchContextSize::Low,
+            "medium" => SearchContextSize::Medium,
+            "high" => SearchContextSize::High,
+            _ => SearchContextSize::Unknown(s),
+        })
+    }
+}
+
+/// Ranking algorithm used by the hosted `file_search` tool
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::Ranker;
+///
+/// let ranker = Ranker::Auto; // Let the API pick the latest ranker
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ranker {
+    /// Latest ranker chosen by the API
+    Auto,
+    /// Ranker released on 2024-11-15
+    Default20241115,
+    /// Unknown ranker (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for Ranker {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Ranker::Auto => serializer.serialize_str("auto"),
+            Ranker::Default20241115 => serializer.serialize_str("default-2024-11-15"),
+            Ranker::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Ranker {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "auto" => Ranker::Auto,
+            "default-2024-11-15" => Ranker::Default20241115,
+            _ => Ranker::Unknown(s),
+        })
+    }
+}
+
+/// Processing state of a vector store
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::VectorStoreStatus;
+///
+/// let ready = VectorStoreStatus::Completed; // Every file has been processed
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VectorStoreStatus {
+    /// Files are still being processed
+    InProgress,
+    /// Ready to be searched
+    Completed,
+    /// Removed by its expiration policy; it can no longer be used
+    Expired,
+    /// Unknown status (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for VectorStoreStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            VectorStoreStatus::InProgress => serializer.serialize_str("in_progress"),
+            VectorStoreStatus::Completed => serializer.serialize_str("completed"),
+            VectorStoreStatus::Expired => serializer.serialize_str("expired"),
+            VectorStoreStatus::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VectorStoreStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "in_progress" => VectorStoreStatus::InProgress,
+            "completed" => VectorStoreStatus::Completed,
+            "expired" => VectorStoreStatus::Expired,
+            _ => VectorStoreStatus::Unknown(s),
+        })
+    }
+}
+
+/// Ingestion state of a file attached to a vector store
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::VectorStoreFileStatus;
+///
+/// let failed = VectorStoreFileStatus::Failed; // See `VectorStoreFile::last_error`
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VectorStoreFileStatus {
+    /// Being chunked and embedded
+    InProgress,
+    /// Searchable
+    Completed,
+    /// Ingestion was cancelled
+    Cancelled,
+    /// Ingestion failed
+    Failed,
+    /// Unknown status (for future compatibility)
+    Unknown(String),
+}
+
+impl serde::Serialize for VectorStoreFileStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            VectorStoreFileStatus::InProgress => serializer.serialize_str("in_progress"),
+            VectorStoreFileStatus::Completed => serializer.serialize_str("completed"),
+            VectorStoreFileStatus::Cancelled => serializer.serialize_str("cancelled"),
+            VectorStoreFileStatus::Failed => serializer.serialize_str("failed"),
+            VectorStoreFileStatus::Unknown(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VectorStoreFileStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "in_progress" => VectorStoreFileStatus::InProgress,
+            "completed" => VectorStoreFileStatus::Completed,
+            "cancelled" => VectorStoreFileStatus::Cancelled,
+            "failed" => VectorStoreFileStatus::Failed,
+            _ => VectorStoreFileStatus::Unknown(s),
+        })
+    }
+}
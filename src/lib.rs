@@ -6,6 +6,10 @@
 //! - Verbosity control for response detail
 //! - Streaming and non-streaming responses
 //! - Type-safe enums for all API parameters
+//! - Composable middleware around every request
+//! - Optional in-memory or on-disk response caching
+//! - OpenAI and Azure OpenAI endpoints (API key or Entra ID tokens)
+//! - A synchronous client behind the `blocking` feature
 //!
 //! ## Quick Start
 //!
@@ -42,6 +46,7 @@
 //!         },
 //!         "required": ["location"]
 //!     })),
+//!     ..Default::default()
 //! };
 //!
 //! let req = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
@@ -52,25 +57,66 @@
 //! ```
 
 // Module declarations
+mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod builder;
+mod cache;
+mod catalog;
+mod citations;
 mod client;
+mod containers;
 mod enums;
+pub mod middleware;
+#[cfg(feature = "mock")]
+pub mod mock;
 mod models;
+mod options;
+mod policy;
+mod redaction;
+mod registry;
 mod requests;
 mod responses;
+mod secret;
+mod streaming;
+mod telemetry;
+mod validation;
+mod vector_stores;
 
 // Re-export all public types for easy access
+pub use crate::auth::{Auth, AzureConfig, Endpoint, TokenProvider};
+pub use crate::builder::Gpt5ClientBuilder;
+pub use crate::cache::{CacheStats, ResponseCache};
+pub use crate::catalog::{ModelList, ModelObject};
+pub use crate::citations::CitationFormat;
 pub use crate::client::Gpt5Client;
+pub use crate::containers::ContainerFile;
 pub use crate::enums::{
-    ContentType, FormatType, OutputType, ReasoningEffort, Role, Status, VerbosityLevel,
+    AllowedToolsMode, ContentType, FormatType, OutputType, Ranker, ReasoningEffort, Role,
+    SearchContextSize, ServiceTier, Status, ToolChoice, Truncation, VectorStoreFileStatus,
+    VectorStoreStatus, VerbosityLevel,
 };
 pub use crate::models::Gpt5Model;
+pub use crate::options::RequestOptions;
+pub use crate::policy::ModelPolicy;
+pub use crate::redaction::{RedactionPolicy, REDACTED};
+pub use crate::registry::{ModelInfo, ModelPricing};
 pub use crate::requests::{
-    Gpt5Request, Gpt5RequestBuilder, RequestReasoning, RequestText, Tool, WebSearchConfig,
+    AttributeFilter, CodeInterpreterContainer, FileSearchConfig, Gpt5Request, Gpt5RequestBuilder,
+    RankingOptions, RequestReasoning, RequestText, Tool, UserLocation, WebSearchConfig,
 };
 pub use crate::responses::{
-    Gpt5Response, InputTokenDetails, OpenAiError, OpenAiErrorDetails, OutputContent,
-    ResponseOutput, ResponseReasoning, ResponseText, ResponseTextFormat, ResponseTokenDetails,
-    ResponseUsage,
+    Annotation, Citation, CodeInterpreterFile, CodeInterpreterOutput, FileCitation,
+    FileSearchResult, Gpt5Response, InputTokenDetails, OpenAiError, OpenAiErrorDetails,
+    OutputContent, ResponseOutput, ResponseReasoning, ResponseText, ResponseTextFormat,
+    ResponseTokenDetails, ResponseUsage, WebSearchAction, WebSearchSource,
+};
+pub use crate::secret::SecretString;
+pub use crate::streaming::{ResponseStream, StreamEvent};
+pub use crate::validation::{Severity, ValidationErrors, ValidationIssue};
+pub use crate::vector_stores::{
+    CreateVectorStore, ExpiresAfter, FileCounts, VectorStore, VectorStoreFile,
+    VectorStoreFileError, VectorStores,
 };
 
 #[cfg(test)]
@@ -94,6 +140,10 @@ mod tests {
 
         let deserialized: ReasoningEffort = serde_json::from_str(&serialized).unwrap();
         assert_eq!(deserialized, ReasoningEffort::Low);
+
+        let minimal: ReasoningEffort = serde_json::from_str("\"minimal\"").unwrap();
+        assert_eq!(minimal, ReasoningEffort::Minimal);
+        assert_eq!(serde_json::to_string(&minimal).unwrap(), "\"minimal\"");
     }
 
     #[test]
@@ -201,6 +251,7 @@ mod tests {
                     "location": {"type": "string"}
                 }
             })),
+            ..Default::default()
         };
 
         let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
@@ -222,7 +273,7 @@ mod tests {
         );
         assert!(request.reasoning.is_some());
         assert!(request.tools.is_some());
-        assert_eq!(request.tool_choice, Some("auto".to_string()));
+        assert_eq!(request.tool_choice, Some(ToolChoice::Auto));
         assert_eq!(request.max_output_tokens, Some(1000));
         assert_eq!(request.top_p, Some(0.9));
         assert!(request.text.is_some());
@@ -447,6 +498,7 @@ mod tests {
                     "param1": {"type": "string"}
                 }
             })),
+            ..Default::default()
         };
 
         assert_eq!(tool.tool_type, "function");
@@ -461,6 +513,7 @@ mod tests {
             name: Some("tool1".to_string()),
             description: Some("First tool".to_string()),
             parameters: Some(json!({})),
+            ..Default::default()
         };
 
         let tool2 = Tool {
@@ -468,6 +521,7 @@ mod tests {
             name: Some("tool2".to_string()),
             description: Some("Second tool".to_string()),
             parameters: Some(json!({})),
+            ..Default::default()
         };
 
         let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
@@ -478,7 +532,7 @@ mod tests {
 
         assert!(request.tools.is_some());
         assert_eq!(request.tools.unwrap().len(), 2);
-        assert_eq!(request.tool_choice, Some("auto".to_string()));
+        assert_eq!(request.tool_choice, Some(ToolChoice::Auto));
     }
 
     #[test]
@@ -552,6 +606,76 @@ mod tests {
         assert_eq!(request.max_output_tokens, Some(5));
     }
 
+    #[test]
+    fn test_build_checked() {
+        let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input(" ")
+            .top_p(1.5)
+            .max_output_tokens(5)
+            .web_search_enabled(true)
+            .web_search_max_results(0)
+            .build_checked()
+            .unwrap_err();
+
+        let fields: Vec<(&str, Severity)> = errors
+            .issues()
+            .iter()
+            .map(|issue| (issue.field.as_str(), issue.severity))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("input", Severity::Error),
+                ("max_output_tokens", Severity::Warning),
+                ("top_p", Severity::Error),
+                ("web_search.max_results", Severity::Error),
+                // From the model registry
+                ("top_p", Severity::Error),
+            ]
+        );
+        assert_eq!(errors.errors().count(), 4);
+        assert_eq!(errors.warnings().count(), 1);
+        assert!(errors
+            .to_string()
+            .starts_with("Invalid GPT-5 request: input: must not be empty; "));
+
+        // Warnings only fail in strict mode
+        let lenient = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Hi")
+            .max_output_tokens(5)
+            .build_checked()
+            .unwrap();
+        assert_eq!(lenient.max_output_tokens, Some(5));
+        let strict = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Hi")
+            .max_output_tokens(5)
+            .strict(true)
+            .build_checked()
+            .unwrap_err();
+        assert_eq!(strict.issues()[0].field, "max_output_tokens");
+
+        // Custom rules see the finished request
+        let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Hi")
+            .rule(|request| {
+                if request.input.len() < 10 {
+                    vec![ValidationIssue::error("input", "too short for this app")]
+                } else {
+                    Vec::new()
+                }
+            })
+            .build_checked()
+            .unwrap_err();
+        assert_eq!(errors.field("input").count(), 1);
+
+        // `build` still returns the request
+        let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Hi")
+            .top_p(1.5)
+            .build();
+        assert_eq!(request.top_p, Some(1.5));
+    }
+
     #[test]
     fn test_web_search_configuration() {
         let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
@@ -597,4 +721,441 @@ mod tests {
 
         assert_eq!(client.api_key, "test-key");
     }
+
+    #[test]
+    fn test_cache_key_is_stable() {
+        let first = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Same prompt")
+            .param("alpha", 1)
+            .param("beta", 2)
+            .build();
+        let second = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Same prompt")
+            .param("beta", 2)
+            .param("alpha", 1)
+            .bypass_cache(true)
+            .build();
+        let different = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Other prompt")
+            .build();
+
+        assert_eq!(ResponseCache::key(&first), ResponseCache::key(&second));
+        assert_ne!(ResponseCache::key(&first), ResponseCache::key(&different));
+        assert_eq!(ResponseCache::key(&first).len(), 16);
+    }
+
+    #[test]
+    fn test_in_memory_cache_evicts_least_recently_used() {
+        let cache = ResponseCache::in_memory(2);
+        let request = |input: &str| {
+            Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+                .input(input)
+                .build()
+        };
+        let response: Gpt5Response =
+            serde_json::from_value(json!({ "status": "completed" })).unwrap();
+
+        cache.put(&request("a"), &response);
+        cache.put(&request("b"), &response);
+        assert!(cache.get(&request("a")).is_some());
+        cache.put(&request("c"), &response);
+
+        assert!(cache.get(&request("a")).unwrap().from_cache);
+        assert!(cache.get(&request("b")).is_none());
+        assert!(cache.get(&request("c")).is_some());
+        assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 1 });
+    }
+
+    #[test]
+    fn test_disk_cache_checks_the_stored_request() {
+        let dir = std::env::temp_dir().join(format!("gpt5-cache-check-{}", std::process::id()));
+        let cache = ResponseCache::on_disk(&dir);
+        let request = |input: &str| {
+            Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+                .input(input)
+                .build()
+        };
+        let response: Gpt5Response =
+            serde_json::from_value(json!({ "status": "completed" })).unwrap();
+
+        // An entry found under another request's key, as after a hash collision
+        cache.put(&request("a"), &response);
+        let path = |input: &str| dir.join(format!("{}.json", ResponseCache::key(&request(input))));
+        std::fs::rename(path("a"), path("b")).unwrap();
+
+        assert!(cache.get(&request("b")).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_api_key_hidden_from_debug_output() {
+        let client = Gpt5Client::new("sk-very-secret".to_string());
+        let debug = format!("{:?}", client);
+
+        assert!(!debug.contains("sk-very-secret"));
+        assert!(debug.contains("[REDACTED]"));
+        assert_eq!(client.api_key.expose_secret(), "sk-very-secret");
+        assert_eq!(client.api_key, "sk-very-secret");
+    }
+
+    #[test]
+    fn test_redaction_policy_patterns_and_callbacks() {
+        let policy = RedactionPolicy::new()
+            .pattern(r"\b\d{3}-\d{2}-\d{4}\b")
+            .unwrap()
+            .callback(|text| text.replace("Alice", "<name>"));
+
+        assert_eq!(
+            policy.redact("Alice's SSN is 123-45-6789"),
+            "<name>'s SSN is [REDACTED]"
+        );
+        assert_eq!(RedactionPolicy::new().redact("untouched"), "untouched");
+        assert_eq!(
+            RedactionPolicy::redact_all().redact("hello"),
+            "[REDACTED 5 bytes]"
+        );
+        assert!(RedactionPolicy::new().pattern("(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_client_from_env_lookup() {
+        let secret_dir = std::env::temp_dir().join(format!("gpt5-env-test-{}", std::process::id()));
+        std::fs::create_dir_all(&secret_dir).unwrap();
+        let key_file = secret_dir.join("api_key");
+        std::fs::write(&key_file, "sk-from-file\n").unwrap();
+
+        let vars: std::collections::HashMap<&str, String> = [
+            ("OPENAI_API_KEY_FILE", key_file.display().to_string()),
+            (
+                "OPENAI_BASE_URL",
+                "https://gateway.example.com/v1/".to_string(),
+            ),
+            ("OPENAI_ORG_ID", "org-123".to_string()),
+            ("OPENAI_PROJECT_ID", "proj_abc".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let client = Gpt5Client::from_lookup(|name| vars.get(name).cloned()).unwrap();
+        assert_eq!(client.api_key, "sk-from-file");
+        assert_eq!(client.base_url, "https://gateway.example.com");
+        assert_eq!(client.organization.as_deref(), Some("org-123"));
+        assert_eq!(client.project.as_deref(), Some("proj_abc"));
+
+        // Plain variables take precedence over their _FILE variants
+        let client = Gpt5Client::from_lookup(|name| match name {
+            "OPENAI_API_KEY" => Some("sk-direct".to_string()),
+            "OPENAI_API_KEY_FILE" => Some("/does/not/exist".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(client.api_key, "sk-direct");
+        assert_eq!(client.base_url, "https://api.openai.com");
+        assert!(client.organization.is_none());
+
+        assert!(Gpt5Client::from_lookup(|_| None).is_err());
+        let _ = std::fs::remove_dir_all(&secret_dir);
+    }
+
+    #[test]
+    fn test_endpoint_url_building() {
+        let base = "https://api.openai.com/";
+        assert_eq!(
+            Endpoint::OpenAi.url(base, "responses"),
+            "https://api.openai.com/v1/responses"
+        );
+
+        let azure = Endpoint::Azure(AzureConfig::deployment("gpt5-prod", "2025-04-01-preview"));
+        let resource = "https://my-resource.openai.azure.com";
+        assert_eq!(
+            azure.url(resource, "responses"),
+            "https://my-resource.openai.azure.com/openai/deployments/gpt5-prod/responses?api-version=2025-04-01-preview"
+        );
+        assert_eq!(
+            azure.url(resource, "models"),
+            "https://my-resource.openai.azure.com/openai/models?api-version=2025-04-01-preview"
+        );
+
+        let azure = Endpoint::Azure(AzureConfig::resource("preview"));
+        assert_eq!(
+            azure.url(resource, "responses"),
+            "https://my-resource.openai.azure.com/openai/responses?api-version=preview"
+        );
+
+        let azure = Endpoint::Azure(AzureConfig::deployment("team/gpt5?beta", "preview"));
+        assert_eq!(
+            azure.url(resource, "responses"),
+            "https://my-resource.openai.azure.com/openai/deployments/team%2Fgpt5%3Fbeta/responses?api-version=preview"
+        );
+    }
+
+    #[test]
+    fn test_client_builder_validation() {
+        let client = Gpt5Client::builder("sk-built")
+            .base_url("http://localhost:9999")
+            .connect_timeout(std::time::Duration::from_secs(2))
+            .pool_max_idle_per_host(4)
+            .proxy("http://proxy.internal:3128")
+            .build()
+            .unwrap();
+        assert_eq!(client.api_key, "sk-built");
+        assert_eq!(client.base_url, "http://localhost:9999");
+
+        let error = Gpt5Client::builder("sk")
+            .proxy("not a url")
+            .build()
+            .unwrap_err();
+        assert!(error.to_string().contains("Invalid proxy URL"));
+
+        let error = Gpt5Client::builder("sk")
+            .root_certificate_pem(
+                b"-----BEGIN CERTIFICATE-----\ngarbage\n-----END CERTIFICATE-----\n",
+            )
+            .build()
+            .unwrap_err();
+        assert!(error.to_string().starts_with("Failed to build HTTP client"));
+    }
+
+    #[test]
+    fn test_model_registry_lookup_and_checks() {
+        let info = Gpt5Model::Gpt5Nano.info().unwrap();
+        assert_eq!(info.id, "gpt-5-nano");
+        assert_eq!(ModelInfo::lookup("gpt-5-nano-2025-08-07"), Some(info));
+        assert_eq!(
+            ModelInfo::lookup("gpt-5-nano-2030-01-31").unwrap().id,
+            "gpt-5-nano"
+        );
+        assert!(ModelInfo::lookup("gpt-5-nano-preview").is_none());
+        assert!(ModelInfo::lookup("ft:gpt-5-mini:acme").is_none());
+        assert!(ModelInfo::lookup("").is_none());
+        assert!(info.matches("gpt-5-nano-2025-08-07"));
+        assert!(!info.matches("gpt-5"));
+        assert_eq!(ModelInfo::all().len(), 3);
+
+        let usage = ResponseUsage {
+            input_tokens: 2_000_000,
+            input_tokens_details: Some(InputTokenDetails {
+                cached_tokens: Some(1_000_000),
+            }),
+            output_tokens: 1_000_000,
+            output_tokens_details: None,
+            total_tokens: 3_000_000,
+        };
+        let cost = ModelInfo::lookup("gpt-5").unwrap().pricing.cost(&usage);
+        assert!((cost - (1.25 + 0.125 + 10.0)).abs() < 1e-9);
+
+        let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+            .input("Hi")
+            .top_p(0.5)
+            .max_output_tokens(200_000)
+            .reasoning_effort(ReasoningEffort::Unknown("extreme".to_string()))
+            .tools(vec![Tool {
+                tool_type: "computer_use_preview".to_string(),
+                name: None,
+                description: None,
+                parameters: None,
+                ..Default::default()
+            }])
+            .build();
+        let issues = ModelInfo::lookup(&request.model)
+            .unwrap()
+            .unsupported(&request);
+        assert_eq!(issues.len(), 4);
+        assert!(issues[0].starts_with("top_p:"));
+        assert!(issues[1].starts_with("max_output_tokens:"));
+        assert!(issues[2].starts_with("reasoning.effort:"));
+        assert!(issues[3].starts_with("tools[0].type:"));
+
+        let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+            .input("Hi")
+            .reasoning_effort(ReasoningEffort::High)
+            .verbosity(VerbosityLevel::Low)
+            .web_search_enabled(true)
+            .build();
+        assert!(ModelInfo::lookup("gpt-5")
+            .unwrap()
+            .unsupported(&request)
+            .is_empty());
+
+        let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Hi")
+            .reasoning_effort(ReasoningEffort::Minimal)
+            .build();
+        assert!(info.unsupported(&request).is_empty());
+    }
+
+    #[test]
+    fn test_render_citations() {
+        let text = "Rust 1.90 ships LLD ([blog.rust-lang.org](https://blog.rust-lang.org/1.90?utm_source=openai)). Cargo <1.90> adds publish --workspace.";
+        let link_start = text.find("[blog").unwrap() as u32;
+        let link_end = text.find(")).").unwrap() as u32 + 1;
+        let cargo_start = text.find("Cargo").unwrap() as u32;
+        let citation = |start: u32, end: u32, url: &str, title: Option<&str>| {
+            json!({
+                "type": "url_citation",
+                "start_index": start,
+                "end_index": end,
+                "url": url,
+                "title": title
+            })
+        };
+        let response: Gpt5Response = serde_json::from_value(json!({
+            "output": [{
+                "type": "message",
+                "content": [
+                    {
+                        "type": "output_text",
+                        "text": text,
+                        "annotations": [
+                            citation(cargo_start, text.chars().count() as u32, "https://doc.rust-lang.org/cargo", None),
+                            citation(link_start, link_end, "https://blog.rust-lang.org/1.90?utm_source=openai", Some("Rust 1.90")),
+                        ]
+                    },
+                    {
+                        "type": "output_text",
+                        "text": "See the release notes.",
+                        "annotations": [
+                            citation(0, 22, "https://blog.rust-lang.org/1.90?utm_source=openai", Some("Rust 1.90")),
+                            citation(0, 99, "https://out.of/range", None),
+                        ]
+                    }
+                ]
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.render_citations(CitationFormat::PlainText),
+            "Rust 1.90 ships LLD [1]. Cargo <1.90> adds publish --workspace. [2]\n\n\
+             See the release notes. [1]\n\n\
+             Sources:\n\
+             [1] Rust 1.90 - https://blog.rust-lang.org/1.90?utm_source=openai\n\
+             [2] https://doc.rust-lang.org/cargo"
+        );
+        assert_eq!(
+            response.render_citations(CitationFormat::Markdown),
+            "Rust 1.90 ships LLD[^1]. Cargo <1.90> adds publish --workspace.[^2]\n\n\
+             See the release notes.[^1]\n\n\
+             [^1]: [Rust 1.90](https://blog.rust-lang.org/1.90?utm_source=openai)\n\
+             [^2]: [https://doc.rust-lang.org/cargo](https://doc.rust-lang.org/cargo)"
+        );
+
+        let html = response.output.as_ref().unwrap()[0]
+            .content
+            .as_ref()
+            .unwrap()[0]
+            .render_citations(CitationFormat::Html);
+        assert_eq!(
+            html,
+            "Rust 1.90 ships LLD<sup><a href=\"#source-1\">[1]</a></sup>. \
+             Cargo &lt;1.90&gt; adds publish --workspace.<sup><a href=\"#source-2\">[2]</a></sup>\n\n\
+             <ol class=\"sources\">\
+             <li id=\"source-1\"><a href=\"https://blog.rust-lang.org/1.90?utm_source=openai\">Rust 1.90</a></li>\
+             <li id=\"source-2\"><a href=\"https://doc.rust-lang.org/cargo\">https://doc.rust-lang.org/cargo</a></li>\
+             </ol>"
+        );
+
+        let unsafe_sources: Gpt5Response = serde_json::from_value(json!({
+            "output": [{"type": "message", "content": [{
+                "type": "output_text",
+                "text": "Two claims.",
+                "annotations": [
+                    citation(0, 3, "javascript:alert(1)", Some("Click")),
+                    citation(4, 10, "https://example.com/a_(b)", Some("Docs [v2]")),
+                ]
+            }]}]
+        }))
+        .unwrap();
+        assert_eq!(
+            unsafe_sources.render_citations(CitationFormat::Markdown),
+            "Two[^1] claims[^2].\n\n\
+             [^1]: Click - javascript:alert\\(1\\)\n\
+             [^2]: [Docs \\[v2\\]](https://example.com/a_\\(b\\))"
+        );
+        assert_eq!(
+            unsafe_sources.render_citations(CitationFormat::Html),
+            "Two<sup><a href=\"#source-1\">[1]</a></sup> claims<sup><a href=\"#source-2\">[2]</a></sup>.\n\n\
+             <ol class=\"sources\">\
+             <li id=\"source-1\">Click - javascript:alert(1)</li>\
+             <li id=\"source-2\"><a href=\"https://example.com/a_(b)\">Docs [v2]</a></li>\
+             </ol>"
+        );
+
+        let empty: Gpt5Response = serde_json::from_value(json!({
+            "output": [{"type": "message", "content": [{"type": "output_text", "text": "No sources."}]}]
+        }))
+        .unwrap();
+        assert_eq!(
+            empty.render_citations(CitationFormat::Markdown),
+            "No sources."
+        );
+    }
+
+    #[test]
+    fn test_file_search_filter_serialization() {
+        let filter: AttributeFilter = serde_json::from_value(json!({
+            "type": "and",
+            "filters": [
+                {"type": "ne", "key": "status", "value": "draft"},
+                {"type": "in", "key": "region", "value": ["eu", "us"]}
+            ]
+        }))
+        .unwrap();
+        let AttributeFilter::And { filters } = &filter else {
+            panic!("expected an and filter, got {:?}", filter);
+        };
+        assert_eq!(filters[0], AttributeFilter::ne("status", "draft"));
+        assert!(matches!(filters[1], AttributeFilter::Unknown(_)));
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap()["filters"][1],
+            json!({"type": "in", "key": "region", "value": ["eu", "us"]})
+        );
+
+        let ranking: RankingOptions =
+            serde_json::from_value(json!({"ranker": "default-2024-11-15"})).unwrap();
+        assert_eq!(ranking.ranker, Some(Ranker::Default20241115));
+        let ranking: RankingOptions =
+            serde_json::from_value(json!({"ranker": "semantic-2026"})).unwrap();
+        assert_eq!(
+            ranking.ranker,
+            Some(Ranker::Unknown("semantic-2026".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_code_interpreter_container_serialization() {
+        let auto: CodeInterpreterContainer =
+            serde_json::from_value(json!({"type": "auto"})).unwrap();
+        assert_eq!(auto, CodeInterpreterContainer::Auto { file_ids: vec![] });
+        assert_eq!(
+            serde_json::to_value(&auto).unwrap(),
+            json!({"type": "auto"})
+        );
+
+        let tool: Tool = serde_json::from_value(json!({
+            "type": "code_interpreter",
+            "container": {"type": "auto", "file_ids": ["file-1"]}
+        }))
+        .unwrap();
+        let container: CodeInterpreterContainer =
+            serde_json::from_value(tool.options["container"].clone()).unwrap();
+        assert_eq!(
+            container,
+            CodeInterpreterContainer::Auto {
+                file_ids: vec!["file-1".to_string()]
+            }
+        );
+        assert!(serde_json::from_value::<CodeInterpreterContainer>(json!(42)).is_err());
+
+        let annotation: Annotation = serde_json::from_value(json!({
+            "type": "container_file_citation",
+            "container_id": "cntr_1",
+            "file_id": "cfile_1"
+        }))
+        .unwrap();
+        assert!(matches!(
+            annotation,
+            Annotation::ContainerFileCitation { filename: None, .. }
+        ));
+    }
 }
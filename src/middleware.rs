@@ -0,0 +1,196 @@
+//! Middleware chain wrapping `Gpt5Client::request`
+//!
+//! Middleware layers run in the order they were added to the client. Each
+//! layer receives the outgoing request and a `Next` handle that invokes the
+//! rest of the chain (and finally the HTTP call). A layer can modify the
+//! request or its headers, inspect or replace the response, or return early
+//! without calling `next` at all (for example to serve a cached answer).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::middleware::{BoxFuture, Middleware, MiddlewareRequest, Next};
+//! use gpt5::{Gpt5Client, Gpt5Response};
+//!
+//! struct LogModel;
+//!
+//! impl Middleware for LogModel {
+//!     fn handle<'a>(
+//!         &'a self,
+//!         req: MiddlewareRequest,
+//!         next: Next<'a>,
+//!     ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+//!         Box::pin(async move {
+//!             println!("calling {}", req.request.model);
+//!             let result = next.run(req).await;
+//!             println!("succeeded: {}", result.is_ok());
+//!             result
+//!         })
+//!     }
+//! }
+//!
+//! let client = Gpt5Client::new("sk-...".to_string()).with_middleware(LogModel);
+//! ```
+
+use crate::client::Gpt5Client;
+use crate::requests::Gpt5Request;
+use crate::responses::Gpt5Response;
+use crate::secret::SecretString;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Boxed, `Send` future returned by middleware layers
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Request flowing through the middleware chain
+#[derive(Debug, Clone)]
+pub struct MiddlewareRequest {
+    /// Request body that will be sent to the API
+    pub request: Gpt5Request,
+    /// Extra HTTP headers attached to the outgoing call
+    pub headers: HeaderMap,
+    /// Timeout for this call, overriding the HTTP client's default
+    pub timeout: Option<Duration>,
+    /// API key used instead of the client's key for this call
+    pub api_key: Option<SecretString>,
+}
+
+/// A single layer in the request pipeline
+pub trait Middleware: Send + Sync {
+    /// Handle a request, usually by delegating to `next.run(req)`
+    fn handle<'a>(
+        &'a self,
+        req: MiddlewareRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>>;
+}
+
+/// The remainder of the middleware chain
+pub struct Next<'a> {
+    client: &'a Gpt5Client,
+    remaining: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(client: &'a Gpt5Client, remaining: &'a [Arc<dyn Middleware>]) -> Self {
+        Self { client, remaining }
+    }
+
+    /// Client the chain belongs to
+    pub(crate) fn client(&self) -> &'a Gpt5Client {
+        self.client
+    }
+
+    /// Invoke the next layer, or send the HTTP request when no layers remain
+    pub fn run(self, req: MiddlewareRequest) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+        match self.remaining.split_first() {
+            Some((layer, rest)) => layer.handle(req, Next::new(self.client, rest)),
+            None => Box::pin(self.client.send(req)),
+        }
+    }
+}
+
+/// Middleware that attaches a fixed set of headers to every request
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::middleware::HeadersMiddleware;
+/// use gpt5::Gpt5Client;
+///
+/// let headers = HeadersMiddleware::new()
+///     .header("x-team", "search")
+///     .expect("valid header");
+/// let client = Gpt5Client::new("sk-...".to_string()).with_middleware(headers);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HeadersMiddleware {
+    headers: HeaderMap,
+}
+
+impl HeadersMiddleware {
+    /// Create an empty header layer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a header, failing if the name or value is not valid HTTP
+    pub fn header(mut self, name: &str, value: &str) -> anyhow::Result<Self> {
+        let name = HeaderName::from_bytes(name.as_bytes())?;
+        let value = HeaderValue::from_str(value)?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+}
+
+impl Middleware for HeadersMiddleware {
+    fn handle<'a>(
+        &'a self,
+        mut req: MiddlewareRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+        for (name, value) in &self.headers {
+            req.headers.insert(name.clone(), value.clone());
+        }
+        next.run(req)
+    }
+}
+
+/// Middleware that merges key/value pairs into the request `metadata` object
+///
+/// Keys already present on the request are left untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::middleware::MetadataMiddleware;
+/// use gpt5::Gpt5Client;
+///
+/// let metadata = MetadataMiddleware::new().entry("service", "billing-bot");
+/// let client = Gpt5Client::new("sk-...".to_string()).with_middleware(metadata);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MetadataMiddleware {
+    entries: Vec<(String, String)>,
+}
+
+impl MetadataMiddleware {
+    /// Create an empty metadata layer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a metadata entry
+    pub fn entry(mut self, key: &str, value: &str) -> Self {
+        self.entries.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl Middleware for MetadataMiddleware {
+    fn handle<'a>(
+        &'a self,
+        mut req: MiddlewareRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+        let metadata = req.request.metadata.get_or_insert_with(Default::default);
+
+        // Fold a raw `metadata` parameter into the typed field so the key is sent once
+        if let Some(Value::Object(raw)) = req.request.parameters.remove("metadata") {
+            for (key, value) in raw {
+                if let Value::String(value) = value {
+                    metadata.entry(key).or_insert(value);
+                }
+            }
+        }
+        for (key, value) in &self.entries {
+            metadata.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+
+        next.run(req)
+    }
+}
@@ -0,0 +1,944 @@
+//! Local mock of the `/v1/responses`, `/v1/models`, `/v1/vector_stores` and
+//! `/v1/containers` endpoints
+//!
+//! `MockServer` speaks just enough HTTP/1.1 to stand in for the OpenAI API in
+//! integration tests and offline examples. Replies are scripted ahead of time
+//! (in code or from a JSON file) and can produce text, function calls,
+//! API errors, artificial latency and SSE streams. Vector stores are kept in
+//! memory; attached files report `in_progress` until the store or file has
+//! been fetched once, so ingestion polling can be exercised. Every container
+//! file downloads as `mock contents of {file_id}`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use gpt5::mock::{MockReply, MockScript, MockServer};
+//! use gpt5::{Gpt5Client, Gpt5Model};
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let script = MockScript::new(vec![MockReply::text("Hello from the mock!")]);
+//!     let server = MockServer::start(script).await?;
+//!
+//!     let client = Gpt5Client::new("test-key".to_string()).with_base_url(server.base_url());
+//!     let reply = client.simple(Gpt5Model::Gpt5Nano, "Hi").await?;
+//!     assert_eq!(reply, "Hello from the mock!");
+//!     Ok(())
+//! }
+//! ```
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// Ordered list of replies served by a `MockServer`
+///
+/// Replies are consumed one per request. Once the script runs out, the last
+/// reply is repeated; an empty script answers every request with a short
+/// placeholder message.
+///
+/// Scripts can be loaded from JSON:
+///
+/// ```json
+/// {
+///   "replies": [
+///     { "text": "Hello there" },
+///     { "function_call": { "name": "get_weather", "arguments": { "location": "Boston" } } },
+///     { "error": { "status": 429 }, "latency_ms": 250 },
+///     { "text": "Streamed answer", "stream": true }
+///   ],
+///   "models": ["gpt-5-mini", "ft:gpt-5-mini:acme::abc123"]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MockScript {
+    /// Replies served in order, one per incoming request
+    #[serde(default)]
+    pub replies: Vec<MockReply>,
+    /// Model ids listed by `GET /v1/models`; defaults to every registry model and snapshot
+    #[serde(default)]
+    pub models: Vec<String>,
+}
+
+/// A single scripted reply
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MockReply {
+    /// Assistant message text placed in an `output_text` content block
+    pub text: Option<String>,
+    /// Function call emitted before any message text
+    pub function_call: Option<MockFunctionCall>,
+    /// API error returned instead of a successful response
+    pub error: Option<MockError>,
+    /// Delay applied before the first byte of the reply is written
+    pub latency_ms: Option<u64>,
+    /// Force (`true`) or suppress (`false`) SSE streaming; defaults to the request's `stream` flag
+    pub stream: Option<bool>,
+    /// Raw response body returned verbatim, bypassing the generated payload
+    pub response: Option<Value>,
+}
+
+/// Scripted function call output
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockFunctionCall {
+    /// Name of the function the model "decided" to call
+    pub name: String,
+    /// Arguments as a JSON object or an already-encoded JSON string
+    #[serde(default)]
+    pub arguments: Value,
+    /// Identifier used to correlate the tool result; generated when omitted
+    pub call_id: Option<String>,
+}
+
+/// Scripted API error
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockError {
+    /// HTTP status code such as 401, 429 or 500
+    pub status: u16,
+    /// Error message; a realistic default is used for common statuses
+    pub message: Option<String>,
+    /// OpenAI error type (for example `invalid_request_error`)
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// OpenAI error code (for example `invalid_api_key`)
+    pub code: Option<String>,
+}
+
+/// Request captured by the mock server, useful for assertions in tests
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// HTTP method (for example `POST`)
+    pub method: String,
+    /// Request path including any query string
+    pub path: String,
+    /// Request headers with lower-cased names
+    pub headers: Vec<(String, String)>,
+    /// Parsed JSON body, or `Value::Null` when the body was empty or not JSON
+    pub body: Value,
+}
+
+impl MockRequest {
+    /// Look up a header value by (case-insensitive) name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Running mock server bound to a local address
+///
+/// The server stops accepting connections when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+    handle: JoinHandle<()>,
+}
+
+struct MockState {
+    script: MockScript,
+    served: Mutex<usize>,
+    requests: Mutex<Vec<MockRequest>>,
+    vector_stores: Mutex<Vec<MockVectorStore>>,
+}
+
+/// In-memory vector store served under `/v1/vector_stores`
+struct MockVectorStore {
+    id: String,
+    created_at: u64,
+    settings: Value,
+    files: Vec<MockVectorStoreFile>,
+}
+
+struct MockVectorStoreFile {
+    id: String,
+    created_at: u64,
+    completed: bool,
+    attributes: Value,
+}
+
+impl MockScript {
+    /// Create a script from a list of replies
+    pub fn new(replies: Vec<MockReply>) -> Self {
+        Self {
+            replies,
+            models: Vec::new(),
+        }
+    }
+
+    /// Replace the model ids served by `GET /v1/models`
+    pub fn with_models<I, S>(mut self, models: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.models = models.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Parse a script from a JSON string
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Load a JSON script from disk
+    pub fn from_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|error| {
+            anyhow::anyhow!("Failed to read mock script {}: {}", path.display(), error)
+        })?;
+
+        Self::from_json(&contents).map_err(|error| {
+            anyhow::anyhow!(
+                "Invalid mock script {} (expected JSON): {}",
+                path.display(),
+                error
+            )
+        })
+    }
+}
+
+impl MockReply {
+    /// Reply with a plain assistant message
+    pub fn text(text: &str) -> Self {
+        Self {
+            text: Some(text.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Reply with a single function call
+    pub fn function_call(name: &str, arguments: Value) -> Self {
+        Self {
+            function_call: Some(MockFunctionCall {
+                name: name.to_string(),
+                arguments,
+                call_id: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Reply with an API error using the given HTTP status
+    pub fn error(status: u16) -> Self {
+        Self {
+            error: Some(MockError {
+                status,
+                message: None,
+                error_type: None,
+                code: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Delay the reply by the given duration
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency_ms = Some(latency.as_millis() as u64);
+        self
+    }
+
+    /// Always stream this reply as server-sent events
+    pub fn streamed(mut self) -> Self {
+        self.stream = Some(true);
+        self
+    }
+}
+
+impl MockServer {
+    /// Start a server on an ephemeral localhost port
+    pub async fn start(script: MockScript) -> anyhow::Result<Self> {
+        Self::bind("127.0.0.1:0".parse()?, script).await
+    }
+
+    /// Start a server on a specific address
+    pub async fn bind(addr: SocketAddr, script: MockScript) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(MockState {
+            script,
+            served: Mutex::new(0),
+            requests: Mutex::new(Vec::new()),
+            vector_stores: Mutex::new(Vec::new()),
+        });
+
+        let accept_state = Arc::clone(&state);
+        let handle = tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(error) => {
+                        // Errors such as running out of file descriptors
+                        // persist for a while; don't spin on them
+                        tracing::warn!("Mock server failed to accept a connection: {}", error);
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        continue;
+                    }
+                };
+                let state = Arc::clone(&accept_state);
+                tokio::spawn(async move {
+                    if let Err(error) = handle_connection(stream, state).await {
+                        tracing::warn!("Mock server connection failed: {}", error);
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            addr,
+            state,
+            handle,
+        })
+    }
+
+    /// Address the server is listening on
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Base URL suitable for `Gpt5Client::with_base_url`
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// All requests received so far, in arrival order
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state
+            .requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    /// Block until the server is stopped (used by the `gpt5-mock` binary)
+    pub async fn wait(mut self) {
+        let _ = (&mut self.handle).await;
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+impl MockState {
+    fn next_reply(&self) -> (usize, MockReply) {
+        let mut served = self
+            .served
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        let index = *served;
+        *served += 1;
+
+        let reply = self
+            .script
+            .replies
+            .get(index)
+            .or_else(|| self.script.replies.last())
+            .cloned()
+            .unwrap_or_else(|| MockReply::text("This is a mock response."));
+
+        (index + 1, reply)
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<MockState>) -> anyhow::Result<()> {
+    let request = match read_request(&mut stream).await? {
+        Some(request) => request,
+        None => return Ok(()),
+    };
+
+    if let Ok(mut requests) = state.requests.lock() {
+        requests.push(request.clone());
+    }
+
+    let path = request.path.split('?').next().unwrap_or_default();
+    if request.method == "GET" {
+        if let Some(route) = path.strip_prefix("/v1/models") {
+            return write_models(&mut stream, &state, route).await;
+        }
+    }
+    if request.method == "GET" {
+        let segments: Vec<&str> = path.split('/').collect();
+        if let ["", "v1", "containers", _, "files", file_id, "content"] = segments.as_slice() {
+            let contents = format!("mock contents of {}", file_id);
+            return write_body(
+                &mut stream,
+                200,
+                "application/octet-stream",
+                contents.as_bytes(),
+            )
+            .await;
+        }
+    }
+    if let Some(route) = path.strip_prefix("/v1/vector_stores") {
+        let (status, body) = vector_store_route(&state, &request, route);
+        return write_json(&mut stream, status, &body).await;
+    }
+    if request.method != "POST" || !path.ends_with("/responses") {
+        let body = error_body(404, Some("Unknown mock endpoint"), None, None);
+        return write_json(&mut stream, 404, &body).await;
+    }
+
+    let (sequence, reply) = state.next_reply();
+
+    if let Some(latency) = reply.latency_ms {
+        tokio::time::sleep(Duration::from_millis(latency)).await;
+    }
+
+    if let Some(error) = &reply.error {
+        let body = error_body(
+            error.status,
+            error.message.as_deref(),
+            error.error_type.as_deref(),
+            error.code.as_deref(),
+        );
+        return write_json(&mut stream, error.status, &body).await;
+    }
+
+    let model = request
+        .body
+        .get("model")
+        .and_then(Value::as_str)
+        .unwrap_or("gpt-5")
+        .to_string();
+    let body = reply
+        .response
+        .clone()
+        .unwrap_or_else(|| response_body(&reply, sequence, &model, &request.body));
+
+    let wants_stream = request
+        .body
+        .get("stream")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    if reply.stream.unwrap_or(wants_stream) {
+        write_event_stream(&mut stream, &reply, &body).await
+    } else {
+        write_json(&mut stream, 200, &body).await
+    }
+}
+
+async fn read_request(stream: &mut TcpStream) -> anyhow::Result<Option<MockRequest>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or("/").to_string();
+
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut body = buffer[header_end..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Ok(Some(MockRequest {
+        method,
+        path,
+        headers,
+        body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+    }))
+}
+
+fn response_body(reply: &MockReply, sequence: usize, model: &str, request: &Value) -> Value {
+    let mut output = Vec::new();
+
+    if let Some(call) = &reply.function_call {
+        let arguments = match &call.arguments {
+            Value::String(encoded) => encoded.clone(),
+            Value::Null => "{}".to_string(),
+            other => other.to_string(),
+        };
+        output.push(json!({
+            "type": "function_call",
+            "id": format!("fc_mock_{}", sequence),
+            "call_id": call.call_id.clone().unwrap_or_else(|| format!("call_mock_{}", sequence)),
+            "name": call.name,
+            "arguments": arguments,
+            "status": "completed"
+        }));
+    }
+
+    if let Some(text) = &reply.text {
+        output.push(json!({
+            "type": "message",
+            "id": format!("msg_mock_{}", sequence),
+            "status": "completed",
+            "role": "assistant",
+            "content": [{ "type": "output_text", "text": text, "annotations": [] }]
+        }));
+    }
+
+    let input_tokens = estimate_tokens(request.get("input").and_then(Value::as_str));
+    let output_tokens = estimate_tokens(reply.text.as_deref());
+    let created_at = unix_time();
+
+    let mut body = json!({
+        "id": format!("resp_mock_{}", sequence),
+        "object": "response",
+        "created_at": created_at,
+        "status": "completed",
+        "model": model,
+        "output": output,
+        "usage": {
+            "input_tokens": input_tokens,
+            "output_tokens": output_tokens,
+            "total_tokens": input_tokens + output_tokens,
+            "output_tokens_details": { "reasoning_tokens": 0 }
+        }
+    });
+
+    // Like the real API, echo the request settings back on the response
+    for key in ECHOED_PARAMETERS {
+        if let Some(value) = request.get(*key) {
+            body[*key] = value.clone();
+        }
+    }
+    if body.get("service_tier") == Some(&json!("auto")) {
+        body["service_tier"] = json!("default");
+    }
+
+    body
+}
+
+const ECHOED_PARAMETERS: &[&str] = &[
+    "instructions",
+    "max_output_tokens",
+    "previous_response_id",
+    "store",
+    "metadata",
+    "tools",
+    "tool_choice",
+    "parallel_tool_calls",
+    "max_tool_calls",
+    "truncation",
+    "service_tier",
+    "user",
+    "safety_identifier",
+    "prompt_cache_key",
+    "background",
+];
+
+fn estimate_tokens(text: Option<&str>) -> u64 {
+    text.map(|text| text.split_whitespace().count() as u64)
+        .unwrap_or(0)
+}
+
+/// Answer `GET /v1/models` (`route` empty) or `GET /v1/models/{id}`
+async fn write_models(
+    stream: &mut TcpStream,
+    state: &MockState,
+    route: &str,
+) -> anyhow::Result<()> {
+    let models = if state.script.models.is_empty() {
+        crate::ModelInfo::all()
+            .iter()
+            .flat_map(|info| std::iter::once(info.id).chain(info.snapshots.iter().copied()))
+            .map(str::to_string)
+            .collect()
+    } else {
+        state.script.models.clone()
+    };
+    let object = |id: &str| {
+        json!({
+            "id": id,
+            "object": "model",
+            "created": 1_754_524_800,
+            "owned_by": if id.starts_with("ft:") { "user" } else { "system" }
+        })
+    };
+
+    match route.strip_prefix('/').map(percent_decode).as_deref() {
+        None | Some("") => {
+            let data: Vec<Value> = models.iter().map(|id| object(id)).collect();
+            write_json(stream, 200, &json!({ "object": "list", "data": data })).await
+        }
+        Some(id) if models.iter().any(|model| model == id) => {
+            write_json(stream, 200, &object(id)).await
+        }
+        Some(id) => {
+            let message = format!(
+                "The model '{}' does not exist or you do not have access to it.",
+                id
+            );
+            let body = error_body(404, Some(&message), None, Some("model_not_found"));
+            write_json(stream, 404, &body).await
+        }
+    }
+}
+
+/// Decode `%XX` escapes in a path segment
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = bytes
+            .get(index + 1..index + 3)
+            .filter(|_| bytes[index] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Answer the `/v1/vector_stores` routes from the in-memory stores
+fn vector_store_route(state: &MockState, request: &MockRequest, route: &str) -> (u16, Value) {
+    let mut stores = state
+        .vector_stores
+        .lock()
+        .unwrap_or_else(|poison| poison.into_inner());
+    let segments: Vec<&str> = route.split('/').filter(|s| !s.is_empty()).collect();
+    let not_found = |what: &str, id: &str| {
+        let message = format!("No {} found with id '{}'.", what, id);
+        (404, error_body(404, Some(&message), None, None))
+    };
+    let list = |data: Vec<Value>| {
+        json!({
+            "object": "list",
+            "first_id": data.first().map(|item| item["id"].clone()),
+            "last_id": data.last().map(|item| item["id"].clone()),
+            "has_more": false,
+            "data": data
+        })
+    };
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("POST", []) => {
+            let created_at = unix_time();
+            let id = (stores.len() + 1..)
+                .map(|n| format!("vs_mock_{}", n))
+                .find(|id| stores.iter().all(|store| store.id != *id))
+                .unwrap_or_default();
+            let files = request.body["file_ids"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(|file_id| MockVectorStoreFile::new(file_id, Value::Null))
+                .collect();
+            stores.push(MockVectorStore {
+                id,
+                created_at,
+                settings: request.body.clone(),
+                files,
+            });
+            (
+                200,
+                stores
+                    .last()
+                    .map(MockVectorStore::to_json)
+                    .unwrap_or_default(),
+            )
+        }
+        ("GET", []) => (
+            200,
+            list(stores.iter().map(MockVectorStore::to_json).collect()),
+        ),
+        (method, [store_id, rest @ ..]) => {
+            let Some(index) = stores.iter().position(|store| store.id == *store_id) else {
+                return not_found("vector store", store_id);
+            };
+            let store = &mut stores[index];
+
+            match (method, rest) {
+                ("GET", []) => {
+                    let body = store.to_json();
+                    store
+                        .files
+                        .iter_mut()
+                        .for_each(|file| file.completed = true);
+                    (200, body)
+                }
+                ("DELETE", []) => {
+                    let id = stores.remove(index).id;
+                    (
+                        200,
+                        json!({ "id": id, "object": "vector_store.deleted", "deleted": true }),
+                    )
+                }
+                ("POST", ["files"]) => {
+                    let file_id = request.body["file_id"].as_str().unwrap_or_default();
+                    let file =
+                        MockVectorStoreFile::new(file_id, request.body["attributes"].clone());
+                    let body = file.to_json(&store.id);
+                    store.files.push(file);
+                    (200, body)
+                }
+                ("GET", ["files"]) => {
+                    let data = store
+                        .files
+                        .iter()
+                        .map(|file| file.to_json(&store.id))
+                        .collect();
+                    (200, list(data))
+                }
+                (_, ["files", file_id]) => {
+                    let Some(position) = store.files.iter().position(|file| file.id == *file_id)
+                    else {
+                        return not_found("file", file_id);
+                    };
+                    if method == "DELETE" {
+                        store.files.remove(position);
+                        let body = json!({ "id": file_id, "object": "vector_store.file.deleted", "deleted": true });
+                        return (200, body);
+                    }
+                    let file = &mut store.files[position];
+                    let body = file.to_json(&store.id);
+                    file.completed = true;
+                    (200, body)
+                }
+                _ => (
+                    404,
+                    error_body(404, Some("Unknown mock endpoint"), None, None),
+                ),
+            }
+        }
+        _ => (
+            404,
+            error_body(404, Some("Unknown mock endpoint"), None, None),
+        ),
+    }
+}
+
+impl MockVectorStore {
+    fn to_json(&self) -> Value {
+        let total = self.files.len();
+        let completed = self.files.iter().filter(|file| file.completed).count();
+        let status = if completed == total {
+            "completed"
+        } else {
+            "in_progress"
+        };
+
+        json!({
+            "id": self.id,
+            "object": "vector_store",
+            "created_at": self.created_at,
+            "name": self.settings.get("name"),
+            "usage_bytes": completed * 1024,
+            "file_counts": {
+                "in_progress": total - completed,
+                "completed": completed,
+                "failed": 0,
+                "cancelled": 0,
+                "total": total
+            },
+            "status": status,
+            "expires_after": self.settings.get("expires_after"),
+            "last_active_at": self.created_at,
+            "metadata": self.settings.get("metadata").cloned().unwrap_or_else(|| json!({}))
+        })
+    }
+}
+
+impl MockVectorStoreFile {
+    fn new(id: &str, attributes: Value) -> Self {
+        Self {
+            id: id.to_string(),
+            created_at: unix_time(),
+            completed: false,
+            attributes,
+        }
+    }
+
+    fn to_json(&self, store_id: &str) -> Value {
+        json!({
+            "id": self.id,
+            "object": "vector_store.file",
+            "created_at": self.created_at,
+            "vector_store_id": store_id,
+            "usage_bytes": if self.completed { 1024 } else { 0 },
+            "status": if self.completed { "completed" } else { "in_progress" },
+            "last_error": null,
+            "attributes": self.attributes
+        })
+    }
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+fn error_body(
+    status: u16,
+    message: Option<&str>,
+    error_type: Option<&str>,
+    code: Option<&str>,
+) -> Value {
+    let (default_message, default_type, default_code) = match status {
+        401 => (
+            "Incorrect API key provided.",
+            "invalid_request_error",
+            Some("invalid_api_key"),
+        ),
+        404 => ("Not found.", "invalid_request_error", None),
+        429 => (
+            "Rate limit reached. Please try again later.",
+            "requests",
+            Some("rate_limit_exceeded"),
+        ),
+        500..=599 => (
+            "The server had an error while processing your request.",
+            "server_error",
+            None,
+        ),
+        _ => ("Mock error.", "invalid_request_error", None),
+    };
+
+    json!({
+        "error": {
+            "message": message.unwrap_or(default_message),
+            "type": error_type.unwrap_or(default_type),
+            "param": null,
+            "code": code.or(default_code)
+        }
+    })
+}
+
+async fn write_json(stream: &mut TcpStream, status: u16, body: &Value) -> anyhow::Result<()> {
+    write_body(
+        stream,
+        status,
+        "application/json",
+        body.to_string().as_bytes(),
+    )
+    .await
+}
+
+async fn write_body(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+) -> anyhow::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason_phrase(status),
+        content_type,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+async fn write_event_stream(
+    stream: &mut TcpStream,
+    reply: &MockReply,
+    body: &Value,
+) -> anyhow::Result<()> {
+    let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n";
+    stream.write_all(head.as_bytes()).await?;
+
+    let mut in_progress = body.clone();
+    in_progress["status"] = json!("in_progress");
+    in_progress["output"] = json!([]);
+    write_event(
+        stream,
+        "response.created",
+        json!({ "response": in_progress }),
+    )
+    .await?;
+
+    if let Some(text) = &reply.text {
+        // Function calls come before the message in the output list
+        let output_index = body["output"]
+            .as_array()
+            .and_then(|output| output.iter().position(|item| item["type"] == "message"))
+            .unwrap_or(0);
+        for delta in text.split_inclusive(' ') {
+            write_event(
+                stream,
+                "response.output_text.delta",
+                json!({ "output_index": output_index, "content_index": 0, "delta": delta }),
+            )
+            .await?;
+        }
+        write_event(
+            stream,
+            "response.output_text.done",
+            json!({ "output_index": output_index, "content_index": 0, "text": text }),
+        )
+        .await?;
+    }
+
+    write_event(stream, "response.completed", json!({ "response": body })).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+async fn write_event(stream: &mut TcpStream, event: &str, mut data: Value) -> anyhow::Result<()> {
+    data["type"] = json!(event);
+    let frame = format!("event: {}\ndata: {}\n\n", event, data);
+    stream.write_all(frame.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
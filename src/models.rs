@@ -1,5 +1,10 @@
 //! GPT-5 Models and related types
 
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
 /// GPT-5 Models available for the /v1/responses endpoint
 ///
 /// # Examples
@@ -9,7 +14,15 @@
 ///
 /// let model = Gpt5Model::Gpt5Nano; // Fastest, most cost-effective
 /// let custom = Gpt5Model::Custom("gpt-5-custom".to_string());
+///
+/// // Names from config files, CLI arguments or env vars
+/// let parsed: Gpt5Model = "gpt-5-mini".parse().unwrap();
+/// assert_eq!(parsed, Gpt5Model::Gpt5Mini);
+/// assert_eq!(parsed.to_string(), "gpt-5-mini");
 /// ```
+///
+/// Models compare and hash by name, so `Custom("gpt-5-mini")` equals
+/// `Gpt5Mini`.
 #[derive(Debug, Clone)]
 pub enum Gpt5Model {
     /// Main GPT-5 model - most capable
@@ -23,6 +36,10 @@ pub enum Gpt5Model {
 }
 
 impl Gpt5Model {
+    /// Every named variant, for menus and validation messages
+    pub const KNOWN: &'static [Gpt5Model] =
+        &[Gpt5Model::Gpt5, Gpt5Model::Gpt5Mini, Gpt5Model::Gpt5Nano];
+
     /// Returns the string representation of the model for API calls
     ///
     /// # Examples
@@ -41,4 +58,61 @@ impl Gpt5Model {
             Gpt5Model::Custom(name) => name,
         }
     }
+
+    /// Named variant for `name`, or `Custom` for anything else
+    pub(crate) fn from_name(name: &str) -> Self {
+        Self::KNOWN
+            .iter()
+            .find(|model| model.as_str() == name)
+            .cloned()
+            .unwrap_or_else(|| Gpt5Model::Custom(name.to_string()))
+    }
+}
+
+impl FromStr for Gpt5Model {
+    type Err = Infallible;
+
+    /// Known names map to their variants; anything else becomes `Custom`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_name(s))
+    }
+}
+
+impl fmt::Display for Gpt5Model {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for Gpt5Model {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Gpt5Model {}
+
+impl Hash for Gpt5Model {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl serde::Serialize for Gpt5Model {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Gpt5Model {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from_name(&s))
+    }
 }
@@ -0,0 +1,77 @@
+//! Per-request overrides for client-wide settings
+
+use crate::secret::SecretString;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::time::Duration;
+
+/// Options that apply to a single call instead of the whole client
+///
+/// Pass them to `Gpt5Client::request_with_options` or
+/// `Gpt5Client::request_stream_with_options`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::RequestOptions;
+/// use std::time::Duration;
+///
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_secs(600))
+///     .idempotency_key("order-42-summary")
+///     .header("x-trace-id", "abc123")
+///     .expect("valid header")
+///     .api_key("sk-tenant-key");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Total timeout for this call, overriding the HTTP client's default
+    pub timeout: Option<Duration>,
+    /// Extra HTTP headers sent with this call
+    pub headers: HeaderMap,
+    /// Value for the `Idempotency-Key` header
+    pub idempotency_key: Option<String>,
+    /// API key used instead of `Gpt5Client::api_key` for this call
+    pub api_key: Option<SecretString>,
+}
+
+impl RequestOptions {
+    /// Create options that leave every client setting untouched
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the request timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Add an HTTP header, failing if the name or value is not valid HTTP
+    pub fn header(mut self, name: &str, value: &str) -> anyhow::Result<Self> {
+        let name = HeaderName::from_bytes(name.as_bytes())?;
+        let value = HeaderValue::from_str(value)?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Send an `Idempotency-Key` header so retries are not billed twice
+    pub fn idempotency_key(mut self, key: &str) -> Self {
+        self.idempotency_key = Some(key.to_string());
+        self
+    }
+
+    /// Authenticate this call with a different API key (for example per tenant)
+    pub fn api_key(mut self, api_key: impl Into<SecretString>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Headers to send, including the idempotency key
+    pub(crate) fn header_map(&self) -> anyhow::Result<HeaderMap> {
+        let mut headers = self.headers.clone();
+        if let Some(key) = &self.idempotency_key {
+            headers.insert("Idempotency-Key", HeaderValue::from_str(key)?);
+        }
+        Ok(headers)
+    }
+}
@@ -0,0 +1,104 @@
+//! Which model names a client will send
+//!
+//! By default `Gpt5Client` only sends requests for `gpt-5*` models. Gateways
+//! that expose fine-tuned ids or their own aliases can widen that with a
+//! `ModelPolicy`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::{Gpt5Client, ModelPolicy};
+//!
+//! let client = Gpt5Client::new("sk-...".to_string())
+//!     .with_base_url("https://llm-gateway.internal".to_string())
+//!     .with_model_policy(ModelPolicy::allowlist(["gpt-5", "acme/gpt-5-*"]));
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+type ModelPredicate = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// Rule deciding which models a client accepts
+#[derive(Clone, Default)]
+pub enum ModelPolicy {
+    /// Only models whose name starts with `gpt-5` (the default)
+    #[default]
+    Gpt5Only,
+    /// Only the listed names; an entry ending in `*` matches any name with that prefix
+    Allowlist(Vec<String>),
+    /// Models for which the function returns `true`
+    Predicate(ModelPredicate),
+    /// Every model; no check is made
+    Disabled,
+}
+
+impl fmt::Debug for ModelPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gpt5Only => f.write_str("Gpt5Only"),
+            Self::Allowlist(models) => f.debug_tuple("Allowlist").field(models).finish(),
+            Self::Predicate(_) => f.write_str("Predicate(..)"),
+            Self::Disabled => f.write_str("Disabled"),
+        }
+    }
+}
+
+impl ModelPolicy {
+    /// Accept only the given names (`*` suffix for prefixes)
+    pub fn allowlist<I, S>(models: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Allowlist(models.into_iter().map(Into::into).collect())
+    }
+
+    /// Accept models for which `predicate` returns `true`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::ModelPolicy;
+    ///
+    /// let policy = ModelPolicy::predicate(|model| model.starts_with("gpt-5") || model.starts_with("ft:gpt-5"));
+    /// assert!(policy.allows("ft:gpt-5-mini:acme::abc123"));
+    /// assert!(!policy.allows("gpt-4o"));
+    /// ```
+    pub fn predicate(predicate: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self::Predicate(Arc::new(predicate))
+    }
+
+    /// Whether a request for `model` may be sent
+    pub fn allows(&self, model: &str) -> bool {
+        match self {
+            Self::Gpt5Only => model.starts_with("gpt-5"),
+            Self::Allowlist(models) => {
+                models
+                    .iter()
+                    .any(|allowed| match allowed.strip_suffix('*') {
+                        Some(prefix) => model.starts_with(prefix),
+                        None => allowed == model,
+                    })
+            }
+            Self::Predicate(predicate) => predicate(model),
+            Self::Disabled => true,
+        }
+    }
+
+    /// Error for a model this policy rejects, or `Ok` if it is allowed
+    pub(crate) fn check(&self, model: &str) -> anyhow::Result<()> {
+        if self.allows(model) {
+            return Ok(());
+        }
+        match self {
+            Self::Gpt5Only => anyhow::bail!("Only GPT-5 models are supported. Got: {}", model),
+            Self::Allowlist(models) => anyhow::bail!(
+                "Model {} is not in the client's allowlist ({})",
+                model,
+                models.join(", ")
+            ),
+            _ => anyhow::bail!("Model {} is rejected by the client's model policy", model),
+        }
+    }
+}
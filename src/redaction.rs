@@ -0,0 +1,111 @@
+//! Redaction of sensitive data in logs
+//!
+//! A `RedactionPolicy` is applied to everything the client writes to
+//! `tracing`: request and response bodies (including input, instructions and
+//! output text) and error messages that echo API payloads. The client's own
+//! API key is always masked, even with an empty policy.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::{Gpt5Client, RedactionPolicy};
+//!
+//! let policy = RedactionPolicy::new()
+//!     .pattern(r"[\w.+-]+@[\w-]+\.[\w.]+")
+//!     .expect("valid regex")
+//!     .callback(|text| text.replace("ACME-SECRET", "[internal]"));
+//!
+//! let client = Gpt5Client::new("sk-...".to_string()).with_redaction(policy);
+//! ```
+
+use regex::Regex;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// Replacement used for redacted matches unless overridden
+pub const REDACTED: &str = "[REDACTED]";
+
+type RedactionCallback = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Ordered set of regex patterns and callbacks used to scrub logged text
+#[derive(Clone)]
+pub struct RedactionPolicy {
+    patterns: Vec<Regex>,
+    callbacks: Vec<RedactionCallback>,
+    replacement: String,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            patterns: Vec::new(),
+            callbacks: Vec::new(),
+            replacement: REDACTED.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for RedactionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedactionPolicy")
+            .field(
+                "patterns",
+                &self.patterns.iter().map(Regex::as_str).collect::<Vec<_>>(),
+            )
+            .field("callbacks", &self.callbacks.len())
+            .field("replacement", &self.replacement)
+            .finish()
+    }
+}
+
+impl RedactionPolicy {
+    /// Create an empty policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy that hides whole bodies, keeping only their length
+    ///
+    /// Useful when payloads must never reach the log aggregator at all.
+    pub fn redact_all() -> Self {
+        Self::new().callback(|text| format!("[REDACTED {} bytes]", text.len()))
+    }
+
+    /// Replace every match of a regular expression
+    pub fn pattern(mut self, pattern: &str) -> anyhow::Result<Self> {
+        self.patterns.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Run a custom transformation over the text after pattern replacement
+    pub fn callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.callbacks.push(Arc::new(callback));
+        self
+    }
+
+    /// Change the text substituted for pattern matches (default `[REDACTED]`)
+    pub fn replacement(mut self, replacement: &str) -> Self {
+        self.replacement = replacement.to_string();
+        self
+    }
+
+    /// Apply the policy to a piece of text
+    pub fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+
+        for pattern in &self.patterns {
+            if let Cow::Owned(replaced) = pattern.replace_all(&text, self.replacement.as_str()) {
+                text = Cow::Owned(replaced);
+            }
+        }
+        for callback in &self.callbacks {
+            text = Cow::Owned(callback(&text));
+        }
+
+        text
+    }
+}
@@ -0,0 +1,284 @@
+//! Static descriptions of the GPT-5 model family
+//!
+//! The registry records what each model accepts (context window, output
+//! limit, parameters, tools) and what it costs, so requests can be checked
+//! before they are sent and usage can be priced afterwards.
+
+use crate::enums::ReasoningEffort;
+use crate::models::Gpt5Model;
+use crate::requests::Gpt5Request;
+use crate::responses::ResponseUsage;
+use crate::validation::ValidationIssue;
+
+/// USD prices per million tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    /// Price of uncached input tokens
+    pub input_per_million: f64,
+    /// Price of input tokens served from the prompt cache
+    pub cached_input_per_million: f64,
+    /// Price of output tokens, including reasoning tokens
+    pub output_per_million: f64,
+}
+
+impl ModelPricing {
+    /// Cost in USD of the tokens reported in `usage`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{ModelInfo, ResponseUsage};
+    ///
+    /// let usage = ResponseUsage {
+    ///     input_tokens: 1_000_000,
+    ///     input_tokens_details: None,
+    ///     output_tokens: 0,
+    ///     output_tokens_details: None,
+    ///     total_tokens: 1_000_000,
+    /// };
+    /// let pricing = ModelInfo::lookup("gpt-5").unwrap().pricing;
+    /// assert_eq!(pricing.cost(&usage), 1.25);
+    /// ```
+    pub fn cost(&self, usage: &ResponseUsage) -> f64 {
+        let cached = usage
+            .input_tokens_details
+            .as_ref()
+            .and_then(|details| details.cached_tokens)
+            .unwrap_or(0)
+            .min(usage.input_tokens);
+        let uncached = usage.input_tokens - cached;
+
+        (f64::from(uncached) * self.input_per_million
+            + f64::from(cached) * self.cached_input_per_million
+            + f64::from(usage.output_tokens) * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// Capabilities and limits of a model
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelInfo {
+    /// Alias that always points at the latest snapshot, e.g. `gpt-5`
+    pub id: &'static str,
+    /// Dated snapshots, newest first, e.g. `gpt-5-2025-08-07`
+    pub snapshots: &'static [&'static str],
+    /// Maximum input plus output tokens
+    pub context_window: u32,
+    /// Maximum output tokens, including reasoning tokens
+    pub max_output_tokens: u32,
+    /// Accepted `reasoning.effort` values
+    pub reasoning_efforts: &'static [ReasoningEffort],
+    /// Whether `text.verbosity` is accepted
+    pub supports_verbosity: bool,
+    /// Whether `top_p` is accepted
+    pub supports_top_p: bool,
+    /// Accepted tool types, e.g. `function` or `web_search`
+    ///
+    /// Strings, like [`Tool::tool_type`](crate::Tool::tool_type) they are compared with.
+    pub tools: &'static [&'static str],
+    /// Token prices
+    pub pricing: ModelPricing,
+}
+
+const REASONING_EFFORTS: &[ReasoningEffort] = &[
+    ReasoningEffort::Minimal,
+    ReasoningEffort::Low,
+    ReasoningEffort::Medium,
+    ReasoningEffort::High,
+];
+const TOOLS: &[&str] = &[
+    "function",
+    "custom",
+    "web_search",
+    "web_search_preview",
+    "file_search",
+    "code_interpreter",
+    "image_generation",
+    "mcp",
+];
+
+static MODELS: &[ModelInfo] = &[
+    ModelInfo {
+        id: "gpt-5",
+        snapshots: &["gpt-5-2025-08-07"],
+        context_window: 400_000,
+        max_output_tokens: 128_000,
+        reasoning_efforts: REASONING_EFFORTS,
+        supports_verbosity: true,
+        supports_top_p: false,
+        tools: TOOLS,
+        pricing: ModelPricing {
+            input_per_million: 1.25,
+            cached_input_per_million: 0.125,
+            output_per_million: 10.0,
+        },
+    },
+    ModelInfo {
+        id: "gpt-5-mini",
+        snapshots: &["gpt-5-mini-2025-08-07"],
+        context_window: 400_000,
+        max_output_tokens: 128_000,
+        reasoning_efforts: REASONING_EFFORTS,
+        supports_verbosity: true,
+        supports_top_p: false,
+        tools: TOOLS,
+        pricing: ModelPricing {
+            input_per_million: 0.25,
+            cached_input_per_million: 0.025,
+            output_per_million: 2.0,
+        },
+    },
+    ModelInfo {
+        id: "gpt-5-nano",
+        snapshots: &["gpt-5-nano-2025-08-07"],
+        context_window: 400_000,
+        max_output_tokens: 128_000,
+        reasoning_efforts: REASONING_EFFORTS,
+        supports_verbosity: true,
+        supports_top_p: false,
+        tools: TOOLS,
+        pricing: ModelPricing {
+            input_per_million: 0.05,
+            cached_input_per_million: 0.005,
+            output_per_million: 0.4,
+        },
+    },
+];
+
+impl ModelInfo {
+    /// Every model in the registry
+    pub fn all() -> &'static [ModelInfo] {
+        MODELS
+    }
+
+    /// Find a model by alias or snapshot name
+    ///
+    /// Unknown dated snapshots of a known model (`gpt-5-mini-2026-01-15`)
+    /// resolve to that model.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::ModelInfo;
+    ///
+    /// assert_eq!(ModelInfo::lookup("gpt-5-mini-2025-08-07").unwrap().id, "gpt-5-mini");
+    /// assert!(ModelInfo::lookup("my-fine-tune").is_none());
+    /// ```
+    pub fn lookup(name: &str) -> Option<&'static ModelInfo> {
+        let exact = MODELS
+            .iter()
+            .find(|info| info.id == name || info.snapshots.contains(&name));
+        exact.or_else(|| {
+            let base = strip_date_suffix(name)?;
+            MODELS.iter().find(|info| info.id == base)
+        })
+    }
+
+    /// Whether `name` is this model's alias or one of its snapshots
+    pub fn matches(&self, name: &str) -> bool {
+        Self::lookup(name).is_some_and(|info| info.id == self.id)
+    }
+
+    /// Parameters in `request` this model does not accept
+    ///
+    /// Each entry names the offending field and explains the problem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Model, Gpt5RequestBuilder, ModelInfo};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .input("Hi")
+    ///     .top_p(0.9)
+    ///     .build();
+    /// let issues = ModelInfo::lookup("gpt-5").unwrap().unsupported(&request);
+    /// assert_eq!(issues.len(), 1);
+    /// assert!(issues[0].starts_with("top_p"));
+    /// ```
+    pub fn unsupported(&self, request: &Gpt5Request) -> Vec<String> {
+        self.unsupported_issues(request)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Errors for parameters in `request` this model does not accept
+    pub(crate) fn unsupported_issues(&self, request: &Gpt5Request) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        if request.top_p.is_some() && !self.supports_top_p {
+            issues.push(ValidationIssue::error(
+                "top_p",
+                format!("not supported by {}", self.id),
+            ));
+        }
+        if let Some(tokens) = request.max_output_tokens {
+            if tokens > self.max_output_tokens {
+                issues.push(ValidationIssue::error(
+                    "max_output_tokens",
+                    format!(
+                        "{} exceeds the {} limit of {}",
+                        tokens, self.id, self.max_output_tokens
+                    ),
+                ));
+            }
+        }
+        if let Some(reasoning) = &request.reasoning {
+            if !self.reasoning_efforts.contains(&reasoning.effort) {
+                let effort = serde_json::to_value(&reasoning.effort).unwrap_or_default();
+                issues.push(ValidationIssue::error(
+                    "reasoning.effort",
+                    format!("{} does not accept {}", self.id, effort),
+                ));
+            }
+        }
+        if let Some(text) = &request.text {
+            if text.verbosity.is_some() && !self.supports_verbosity {
+                issues.push(ValidationIssue::error(
+                    "text.verbosity",
+                    format!("not supported by {}", self.id),
+                ));
+            }
+        }
+        for (index, tool) in request.tools.iter().flatten().enumerate() {
+            if !self.tools.contains(&tool.tool_type.as_str()) {
+                issues.push(ValidationIssue::error(
+                    format!("tools[{}].type", index),
+                    format!("{} does not support \"{}\" tools", self.id, tool.tool_type),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+impl Gpt5Model {
+    /// Registry entry for this model, if it is a known GPT-5 model or snapshot
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Model;
+    ///
+    /// let info = Gpt5Model::Gpt5Mini.info().unwrap();
+    /// assert_eq!(info.context_window, 400_000);
+    /// ```
+    pub fn info(&self) -> Option<&'static ModelInfo> {
+        ModelInfo::lookup(self.as_str())
+    }
+}
+
+/// `gpt-5-mini-2025-08-07` -> `gpt-5-mini`
+fn strip_date_suffix(name: &str) -> Option<&str> {
+    let at = name.len().checked_sub(11)?;
+    let base = name.get(..at)?;
+    let date = name.get(at..)?.strip_prefix('-')?;
+    let is_date = date.len() == 10
+        && date.char_indices().all(|(index, c)| match index {
+            4 | 7 => c == '-',
+            _ => c.is_ascii_digit(),
+        });
+    is_date.then_some(base)
+}
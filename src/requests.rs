@@ -1,6 +1,10 @@
 //! Request structures and builders for GPT-5 API
 
-use crate::enums::{ReasoningEffort, VerbosityLevel};
+use crate::enums::{
+    Ranker, ReasoningEffort, SearchContextSize, ServiceTier, ToolChoice, Truncation, VerbosityLevel,
+};
+use crate::registry::ModelInfo;
+use crate::validation::{Severity, ValidationErrors, ValidationIssue, ValidationRule};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -40,7 +44,7 @@ pub struct Gpt5Request {
     pub tools: Option<Vec<Tool>>,
     /// Strategy directing the model to auto-select or force a specific tool
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tool_choice: Option<String>,
+    pub tool_choice: Option<ToolChoice>,
     /// Maximum number of tokens the model is allowed to emit
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_output_tokens: Option<u32>,
@@ -53,10 +57,49 @@ pub struct Gpt5Request {
     /// System-level instructions that frame how the model should respond
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instructions: Option<String>,
+    /// Earlier response this request continues, for multi-turn conversations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+    /// Whether OpenAI may store the response for later retrieval (defaults to `true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+    /// Up to 16 key/value pairs attached to the response (keys ≤ 64, values ≤ 512 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// Whether the model may run several tool calls at once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+    /// Maximum number of built-in tool calls the model may make in this response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tool_calls: Option<u32>,
+    /// What to do when the input exceeds the context window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation: Option<Truncation>,
+    /// Processing tier to serve the request with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
+    /// End-user identifier (superseded by `safety_identifier` and `prompt_cache_key`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Stable, hashed identifier of the end user for abuse detection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_identifier: Option<String>,
+    /// Key grouping similar requests to improve prompt cache hit rates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_cache_key: Option<String>,
+    /// Run the response asynchronously; poll it by id until it completes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<bool>,
+    /// Extra output data to return, e.g. `web_search_call.action.sources`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
     /// Derived configuration describing desired web search behaviour for tool calls
     #[serde(skip_serializing)]
     #[serde(skip_deserializing)]
     pub web_search_config: Option<WebSearchConfig>,
+    /// Skip cache lookups for this request (a fresh response still refreshes the cache)
+    #[serde(skip)]
+    pub bypass_cache: bool,
     /// Additional arbitrary parameters forwarded to the API
     #[serde(flatten)]
     pub parameters: HashMap<String, Value>,
@@ -70,32 +113,329 @@ pub struct RequestReasoning {
 }
 
 /// Text configuration for requests
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RequestText {
     /// Requested verbosity for natural language output
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verbosity: Option<VerbosityLevel>,
+    /// Output format such as `{"type": "json_object"}` or a JSON schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<Value>,
 }
 
 /// Configuration for enabling web search assistance.
 ///
-/// When enabled the GPT-5 API may request the `web_search` tool. This configuration
-/// stores metadata (like a suggested query or result cap) so your application can
-/// honour those preferences when fulfilling tool calls.
+/// When enabled the GPT-5 API may request the `web_search` tool.
+/// `search_context_size`, `user_location` and `allowed_domains` are sent to
+/// the API as part of the tool definition. The API has no deny list, so
+/// only an allow list of domains is available.
+///
+/// `name`, `description`, `query` and `max_results` are client-side only:
+/// they are never sent, and stay on `Gpt5Request::web_search_config` so
+/// your application can honour those preferences itself.
 #[derive(Debug, Clone, Default)]
 pub struct WebSearchConfig {
     /// Indicates whether the generated request should expose the `web_search` tool
     pub enabled: bool,
-    /// Human-readable name to surface when presenting the tool to end-users
+    /// How much retrieved web content the model sees (sent to the API)
+    pub search_context_size: Option<SearchContextSize>,
+    /// Approximate location used to localize results (sent to the API)
+    pub user_location: Option<UserLocation>,
+    /// Restrict results to these domains and their subdomains, at most 20 (sent to the API)
+    pub allowed_domains: Vec<String>,
+    /// Human-readable name to surface when presenting the tool to end-users (client-side only)
     pub name: Option<String>,
-    /// Summary explaining what the search helper does
+    /// Summary explaining what the search helper does (client-side only)
     pub description: Option<String>,
-    /// Suggested search query to run if the tool is invoked
+    /// Suggested search query to run if the tool is invoked (client-side only)
     pub query: Option<String>,
-    /// Preferred maximum number of results the search integration should return
+    /// Preferred maximum number of results the search integration should return (client-side only)
     pub max_results: Option<u8>,
 }
 
+/// Approximate end-user location for localizing web search results
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::UserLocation;
+///
+/// let location = UserLocation {
+///     country: Some("GB".to_string()),
+///     city: Some("London".to_string()),
+///     timezone: Some("Europe/London".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserLocation {
+    /// Two-letter ISO country code, e.g. `US`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Free-text city name, e.g. `San Francisco`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// Free-text region or state, e.g. `California`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// IANA timezone, e.g. `America/Los_Angeles`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// Configuration for the hosted `file_search` tool
+///
+/// Every field is sent to the API as part of the tool definition. Create
+/// and fill vector stores with [`Gpt5Client::vector_stores`](crate::Gpt5Client::vector_stores).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileSearchConfig {
+    /// Vector stores to search
+    pub vector_store_ids: Vec<String>,
+    /// Maximum number of chunks returned to the model, between 1 and 50
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+    /// Ranker and minimum relevance score for returned chunks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_options: Option<RankingOptions>,
+    /// Only search files whose attributes match this filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<AttributeFilter>,
+}
+
+/// How `file_search` ranks the chunks it retrieves
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RankingOptions {
+    /// Ranking algorithm; the API picks one when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranker: Option<Ranker>,
+    /// Drop chunks scoring below this value, between 0.0 and 1.0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_threshold: Option<f64>,
+}
+
+/// Filter on the attributes of vector store files
+///
+/// Comparisons match one attribute against a string, number or boolean;
+/// `And`/`Or` combine other filters.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::AttributeFilter;
+/// use serde_json::json;
+///
+/// let filter = AttributeFilter::and([
+///     AttributeFilter::eq("team", "billing"),
+///     AttributeFilter::gte("year", 2024),
+/// ]);
+/// assert_eq!(
+///     serde_json::to_value(&filter).unwrap(),
+///     json!({
+///         "type": "and",
+///         "filters": [
+///             {"type": "eq", "key": "team", "value": "billing"},
+///             {"type": "gte", "key": "year", "value": 2024}
+///         ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AttributeFilter {
+    /// Attribute equals `value`
+    Eq {
+        /// Attribute name
+        key: String,
+        /// Value to compare with
+        value: Value,
+    },
+    /// Attribute does not equal `value`
+    Ne {
+        /// Attribute name
+        key: String,
+        /// Value to compare with
+        value: Value,
+    },
+    /// Attribute is greater than `value`
+    Gt {
+        /// Attribute name
+        key: String,
+        /// Value to compare with
+        value: Value,
+    },
+    /// Attribute is greater than or equal to `value`
+    Gte {
+        /// Attribute name
+        key: String,
+        /// Value to compare with
+        value: Value,
+    },
+    /// Attribute is less than `value`
+    Lt {
+        /// Attribute name
+        key: String,
+        /// Value to compare with
+        value: Value,
+    },
+    /// Attribute is less than or equal to `value`
+    Lte {
+        /// Attribute name
+        key: String,
+        /// Value to compare with
+        value: Value,
+    },
+    /// Every filter matches
+    And {
+        /// Filters that must all match
+        filters: Vec<AttributeFilter>,
+    },
+    /// At least one filter matches
+    Or {
+        /// Filters of which one must match
+        filters: Vec<AttributeFilter>,
+    },
+    /// Unknown filter (for future compatibility)
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl AttributeFilter {
+    /// `key` equals `value`
+    pub fn eq(key: &str, value: impl Into<Value>) -> Self {
+        AttributeFilter::Eq {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// `key` does not equal `value`
+    pub fn ne(key: &str, value: impl Into<Value>) -> Self {
+        AttributeFilter::Ne {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// `key` is greater than `value`
+    pub fn gt(key: &str, value: impl Into<Value>) -> Self {
+        AttributeFilter::Gt {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// `key` is greater than or equal to `value`
+    pub fn gte(key: &str, value: impl Into<Value>) -> Self {
+        AttributeFilter::Gte {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// `key` is less than `value`
+    pub fn lt(key: &str, value: impl Into<Value>) -> Self {
+        AttributeFilter::Lt {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// `key` is less than or equal to `value`
+    pub fn lte(key: &str, value: impl Into<Value>) -> Self {
+        AttributeFilter::Lte {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// Every filter matches
+    pub fn and(filters: impl IntoIterator<Item = AttributeFilter>) -> Self {
+        AttributeFilter::And {
+            filters: filters.into_iter().collect(),
+        }
+    }
+
+    /// At least one filter matches
+    pub fn or(filters: impl IntoIterator<Item = AttributeFilter>) -> Self {
+        AttributeFilter::Or {
+            filters: filters.into_iter().collect(),
+        }
+    }
+}
+
+/// Container the hosted `code_interpreter` tool runs code in
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::CodeInterpreterContainer;
+/// use serde_json::json;
+///
+/// let auto = CodeInterpreterContainer::Auto {
+///     file_ids: vec!["file-sales".to_string()],
+/// };
+/// assert_eq!(
+///     serde_json::to_value(&auto).unwrap(),
+///     json!({"type": "auto", "file_ids": ["file-sales"]})
+/// );
+///
+/// let existing = CodeInterpreterContainer::Id("cntr_abc123".to_string());
+/// assert_eq!(serde_json::to_value(&existing).unwrap(), json!("cntr_abc123"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeInterpreterContainer {
+    /// Container managed by the API, preloaded with these uploaded files
+    Auto {
+        /// Uploaded files copied into the container
+        file_ids: Vec<String>,
+    },
+    /// Existing container created with the Containers API
+    Id(String),
+}
+
+impl Serialize for CodeInterpreterContainer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        match self {
+            CodeInterpreterContainer::Id(id) => serializer.serialize_str(id),
+            CodeInterpreterContainer::Auto { file_ids } => {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry("type", "auto")?;
+                if !file_ids.is_empty() {
+                    map.serialize_entry("file_ids", file_ids)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeInterpreterContainer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(id) => Ok(CodeInterpreterContainer::Id(id)),
+            Value::Object(fields) => {
+                let file_ids = match fields.get("file_ids") {
+                    Some(ids) => Vec::deserialize(ids).map_err(serde::de::Error::custom)?,
+                    None => Vec::new(),
+                };
+                Ok(CodeInterpreterContainer::Auto { file_ids })
+            }
+            other => Err(serde::de::Error::custom(format!(
+                "expected a container id or object, got {}",
+                other
+            ))),
+        }
+    }
+}
+
 /// Tool definition for function calling
 ///
 /// Defines a tool that GPT-5 can call during its response generation.
@@ -116,11 +456,12 @@ pub struct WebSearchConfig {
 ///             "location": {"type": "string"}
 ///         }
 ///     })),
+///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Tool {
-    /// Kind of tool being offered (OpenAI expects "function" or "web_search")
+    /// Kind of tool being offered, e.g. "function" or "web_search"
     #[serde(rename = "type")]
     pub tool_type: String,
     /// Public identifier for the tool, primarily used for function calling routing
@@ -132,6 +473,9 @@ pub struct Tool {
     /// JSON Schema describing the arguments the tool expects
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Value>,
+    /// Tool-specific settings such as `search_context_size`, sent alongside `type`
+    #[serde(flatten)]
+    pub options: HashMap<String, Value>,
 }
 
 /// Builder for GPT-5 requests using /v1/responses
@@ -156,13 +500,30 @@ pub struct Gpt5RequestBuilder {
     input: String,
     reasoning: Option<RequestReasoning>,
     tools: Option<Vec<Tool>>,
-    tool_choice: Option<String>,
+    tool_choice: Option<ToolChoice>,
     max_output_tokens: Option<u32>,
     top_p: Option<f64>,
     text: Option<RequestText>,
     instructions: Option<String>,
+    previous_response_id: Option<String>,
+    store: Option<bool>,
+    metadata: Option<HashMap<String, String>>,
+    parallel_tool_calls: Option<bool>,
+    max_tool_calls: Option<u32>,
+    truncation: Option<Truncation>,
+    service_tier: Option<ServiceTier>,
+    user: Option<String>,
+    safety_identifier: Option<String>,
+    prompt_cache_key: Option<String>,
+    background: Option<bool>,
+    include: Option<Vec<String>>,
     web_search: Option<WebSearchConfig>,
+    file_search: Option<FileSearchConfig>,
+    code_interpreter: Option<CodeInterpreterContainer>,
+    bypass_cache: bool,
     parameters: HashMap<String, Value>,
+    strict: bool,
+    rules: Vec<ValidationRule>,
 }
 
 impl Gpt5RequestBuilder {
@@ -190,8 +551,25 @@ impl Gpt5RequestBuilder {
             top_p: None,
             text: None,
             instructions: None,
+            previous_response_id: None,
+            store: None,
+            metadata: None,
+            parallel_tool_calls: None,
+            max_tool_calls: None,
+            truncation: None,
+            service_tier: None,
+            user: None,
+            safety_identifier: None,
+            prompt_cache_key: None,
+            background: None,
+            include: None,
             web_search: None,
+            file_search: None,
+            code_interpreter: None,
+            bypass_cache: false,
             parameters: HashMap::new(),
+            strict: false,
+            rules: Vec::new(),
         }
     }
 
@@ -224,30 +602,242 @@ impl Gpt5RequestBuilder {
     }
 
     /// Provide a suggested query for the web search tool description.
+    ///
+    /// Client-side only; the query is not sent to the API.
     pub fn web_search_query(mut self, query: &str) -> Self {
-        let mut config = self.web_search.unwrap_or_else(|| WebSearchConfig {
-            enabled: true,
-            ..Default::default()
-        });
-        config.query = Some(query.to_string());
-        if !config.enabled {
-            config.enabled = true;
-        }
-        self.web_search = Some(config);
+        self.web_search_mut().query = Some(query.to_string());
         self
     }
 
     /// Suggest how many search results should be returned when fulfilling the tool call.
+    ///
+    /// Client-side only; the API does not accept a result cap.
     pub fn web_search_max_results(mut self, max_results: u8) -> Self {
-        let mut config = self.web_search.unwrap_or_else(|| WebSearchConfig {
-            enabled: true,
-            ..Default::default()
-        });
-        config.max_results = Some(max_results);
-        if !config.enabled {
-            config.enabled = true;
+        self.web_search_mut().max_results = Some(max_results);
+        self
+    }
+
+    /// Ask for the sources each web search consulted, enabling web search.
+    ///
+    /// Adds `web_search_call.action.sources` to `include`; read them with
+    /// [`WebSearchAction::sources`](crate::WebSearchAction::sources).
+    /// Passing `false` only removes that entry; web search stays as it was.
+    pub fn web_search_sources(mut self, sources: bool) -> Self {
+        const SOURCES: &str = "web_search_call.action.sources";
+
+        if sources {
+            self.web_search_mut();
+            return self.include(SOURCES);
+        }
+        if let Some(include) = &mut self.include {
+            include.retain(|item| item != SOURCES);
+            if include.is_empty() {
+                self.include = None;
+            }
+        }
+        self
+    }
+
+    /// Request extra output data, e.g. `web_search_call.action.sources`
+    pub fn include(mut self, item: &str) -> Self {
+        let include = self.include.get_or_insert_with(Vec::new);
+        if !include.iter().any(|existing| existing == item) {
+            include.push(item.to_string());
+        }
+        self
+    }
+
+    /// Choose how much retrieved web content the model sees, enabling web search.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model, SearchContextSize, UserLocation};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .input("Best ramen near me?")
+    ///     .web_search_context_size(SearchContextSize::Low)
+    ///     .web_search_user_location(UserLocation {
+    ///         country: Some("JP".to_string()),
+    ///         city: Some("Tokyo".to_string()),
+    ///         ..Default::default()
+    ///     })
+    ///     .web_search_allowed_domains(["tabelog.com"])
+    ///     .build();
+    ///
+    /// let tool = &request.tools.unwrap()[0];
+    /// assert_eq!(tool.options["search_context_size"], "low");
+    /// assert_eq!(tool.options["user_location"]["type"], "approximate");
+    /// ```
+    pub fn web_search_context_size(mut self, size: SearchContextSize) -> Self {
+        self.web_search_mut().search_context_size = Some(size);
+        self
+    }
+
+    /// Localize web search results to an approximate location, enabling web search.
+    pub fn web_search_user_location(mut self, location: UserLocation) -> Self {
+        self.web_search_mut().user_location = Some(location);
+        self
+    }
+
+    /// Only search these domains (and their subdomains), enabling web search.
+    ///
+    /// Domains are given without a scheme, e.g. `docs.rs`. The API accepts up to 20.
+    pub fn web_search_allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.web_search_mut()
+            .allowed_domains
+            .extend(domains.into_iter().map(Into::into));
+        self
+    }
+
+    /// Web search configuration, enabled, creating it if needed
+    fn web_search_mut(&mut self) -> &mut WebSearchConfig {
+        let config = self.web_search.get_or_insert_with(WebSearchConfig::default);
+        config.enabled = true;
+        config
+    }
+
+    /// Let the model search these vector stores with the hosted `file_search` tool
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{AttributeFilter, Gpt5Model, Gpt5RequestBuilder};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .input("What is our refund window?")
+    ///     .file_search(["vs_handbook"])
+    ///     .file_search_max_results(5)
+    ///     .file_search_filter(AttributeFilter::eq("team", "support"))
+    ///     .file_search_results(true)
+    ///     .build();
+    ///
+    /// let tool = &request.tools.unwrap()[0];
+    /// assert_eq!(tool.tool_type, "file_search");
+    /// assert_eq!(tool.options["vector_store_ids"][0], "vs_handbook");
+    /// assert_eq!(tool.options["max_num_results"], 5);
+    /// ```
+    pub fn file_search<I, S>(mut self, vector_store_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.file_search_mut()
+            .vector_store_ids
+            .extend(vector_store_ids.into_iter().map(Into::into));
+        self
+    }
+
+    /// Cap how many chunks `file_search` returns to the model (1 to 50)
+    pub fn file_search_max_results(mut self, max_results: u32) -> Self {
+        self.file_search_mut().max_num_results = Some(max_results);
+        self
+    }
+
+    /// Choose the ranker and minimum score for `file_search` results
+    pub fn file_search_ranking(mut self, ranking: RankingOptions) -> Self {
+        self.file_search_mut().ranking_options = Some(ranking);
+        self
+    }
+
+    /// Only search vector store files whose attributes match `filter`
+    pub fn file_search_filter(mut self, filter: AttributeFilter) -> Self {
+        self.file_search_mut().filters = Some(filter);
+        self
+    }
+
+    /// Ask for the chunks each file search retrieved
+    ///
+    /// Adds `file_search_call.results` to `include`; read them from
+    /// [`ResponseOutput::results`](crate::ResponseOutput::results).
+    pub fn file_search_results(mut self, results: bool) -> Self {
+        const RESULTS: &str = "file_search_call.results";
+
+        if results {
+            return self.include(RESULTS);
+        }
+        if let Some(include) = &mut self.include {
+            include.retain(|item| item != RESULTS);
+        }
+        self
+    }
+
+    /// File search configuration, creating it if needed
+    fn file_search_mut(&mut self) -> &mut FileSearchConfig {
+        self.file_search
+            .get_or_insert_with(FileSearchConfig::default)
+    }
+
+    /// Let the model run Python with the hosted `code_interpreter` tool
+    ///
+    /// Uses a container managed by the API. Files the code writes can be
+    /// fetched with [`Gpt5Client::download_container_files`](crate::Gpt5Client::download_container_files).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Model, Gpt5RequestBuilder};
+    /// use serde_json::json;
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .input("Plot monthly revenue from sales.csv as a PNG")
+    ///     .code_interpreter_files(["file-sales"])
+    ///     .build();
+    ///
+    /// let tool = &request.tools.unwrap()[0];
+    /// assert_eq!(tool.tool_type, "code_interpreter");
+    /// assert_eq!(tool.options["container"], json!({"type": "auto", "file_ids": ["file-sales"]}));
+    /// ```
+    pub fn code_interpreter(mut self) -> Self {
+        if self.code_interpreter.is_none() {
+            self.code_interpreter = Some(CodeInterpreterContainer::Auto {
+                file_ids: Vec::new(),
+            });
+        }
+        self
+    }
+
+    /// Preload uploaded files into an API-managed `code_interpreter` container
+    ///
+    /// Replaces a container set with
+    /// [`code_interpreter_container`](Self::code_interpreter_container).
+    pub fn code_interpreter_files<I, S>(mut self, file_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut ids = match self.code_interpreter.take() {
+            Some(CodeInterpreterContainer::Auto { file_ids }) => file_ids,
+            _ => Vec::new(),
+        };
+        ids.extend(file_ids.into_iter().map(Into::into));
+        self.code_interpreter = Some(CodeInterpreterContainer::Auto { file_ids: ids });
+        self
+    }
+
+    /// Run `code_interpreter` in an existing container, e.g. `cntr_abc123`
+    pub fn code_interpreter_container(mut self, container_id: &str) -> Self {
+        self.code_interpreter = Some(CodeInterpreterContainer::Id(container_id.to_string()));
+        self
+    }
+
+    /// Ask for the logs and images of each code interpreter call
+    ///
+    /// Adds `code_interpreter_call.outputs` to `include`; read them from
+    /// [`ResponseOutput::outputs`](crate::ResponseOutput::outputs).
+    pub fn code_interpreter_outputs(mut self, outputs: bool) -> Self {
+        const OUTPUTS: &str = "code_interpreter_call.outputs";
+
+        if outputs {
+            return self.include(OUTPUTS);
+        }
+        if let Some(include) = &mut self.include {
+            include.retain(|item| item != OUTPUTS);
         }
-        self.web_search = Some(config);
         self
     }
 
@@ -308,6 +898,7 @@ impl Gpt5RequestBuilder {
     ///     name: Some("get_weather".to_string()),
     ///     description: Some("Get weather".to_string()),
     ///     parameters: Some(json!({})),
+    ///     ..Default::default()
     /// };
     ///
     /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
@@ -323,19 +914,23 @@ impl Gpt5RequestBuilder {
     ///
     /// # Arguments
     ///
-    /// * `choice` - Tool choice strategy ("auto", "none", or specific tool name)
+    /// * `choice` - A [`ToolChoice`], or `"auto"`, `"none"` or `"required"`
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model, ToolChoice};
     ///
     /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
     ///     .tool_choice("auto")
     ///     .build();
+    ///
+    /// let forced = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .tool_choice(ToolChoice::Function("get_weather".to_string()))
+    ///     .build();
     /// ```
-    pub fn tool_choice(mut self, choice: &str) -> Self {
-        self.tool_choice = Some(choice.to_string());
+    pub fn tool_choice(mut self, choice: impl Into<ToolChoice>) -> Self {
+        self.tool_choice = Some(choice.into());
         self
     }
 
@@ -375,9 +970,81 @@ impl Gpt5RequestBuilder {
     ///     .build();
     /// ```
     pub fn verbosity(mut self, level: VerbosityLevel) -> Self {
-        self.text = Some(RequestText {
-            verbosity: Some(level),
-        });
+        self.text.get_or_insert_with(RequestText::default).verbosity = Some(level);
+        self
+    }
+
+    /// Ask for a JSON object instead of free-form text
+    ///
+    /// The instructions or input should still mention JSON, as the API
+    /// requires for JSON mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///     .input("List three colors as JSON")
+    ///     .json_output()
+    ///     .build();
+    /// ```
+    pub fn json_output(mut self) -> Self {
+        self.text.get_or_insert_with(RequestText::default).format =
+            Some(serde_json::json!({ "type": "json_object" }));
+        self
+    }
+
+    /// Constrain the output to a JSON schema (structured outputs)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the schema
+    /// * `schema` - JSON schema the output must match
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    /// use serde_json::json;
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///     .input("Give me a color")
+    ///     .json_schema("color", json!({
+    ///         "type": "object",
+    ///         "properties": {"name": {"type": "string"}},
+    ///         "required": ["name"],
+    ///         "additionalProperties": false
+    ///     }))
+    ///     .build();
+    /// ```
+    pub fn json_schema(mut self, name: &str, schema: Value) -> Self {
+        self.text.get_or_insert_with(RequestText::default).format = Some(serde_json::json!({
+            "type": "json_schema",
+            "name": name,
+            "schema": schema,
+            "strict": true,
+        }));
+        self
+    }
+
+    /// Continue the conversation of an earlier response
+    ///
+    /// The API reuses that response's input and output as context, so only
+    /// the new turn needs to be sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .input("And what about Germany?")
+    ///     .previous_response_id("resp_123")
+    ///     .build();
+    /// ```
+    pub fn previous_response_id(mut self, id: &str) -> Self {
+        self.previous_response_id = Some(id.to_string());
         self
     }
 
@@ -421,6 +1088,112 @@ impl Gpt5RequestBuilder {
         self
     }
 
+    /// Set whether OpenAI stores the response (`store: false` for zero retention)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///     .input("Hello")
+    ///     .store(false)
+    ///     .build();
+    /// assert_eq!(request.store, Some(false));
+    /// ```
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Attach a metadata key/value pair to the response
+    ///
+    /// The API accepts up to 16 pairs with keys of at most 64 and values of
+    /// at most 512 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///     .input("Hello")
+    ///     .metadata("tenant", "acme")
+    ///     .metadata("request_id", "req-42")
+    ///     .build();
+    /// assert_eq!(request.metadata.unwrap()["tenant"], "acme");
+    /// ```
+    pub fn metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Allow or forbid several tool calls in one turn
+    pub fn parallel_tool_calls(mut self, parallel: bool) -> Self {
+        self.parallel_tool_calls = Some(parallel);
+        self
+    }
+
+    /// Cap the number of built-in tool calls (web search, file search, ...)
+    pub fn max_tool_calls(mut self, max: u32) -> Self {
+        self.max_tool_calls = Some(max);
+        self
+    }
+
+    /// Choose what happens when the input exceeds the context window
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Choose the processing tier
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model, ServiceTier};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+    ///     .input("Summarize this quarter's incidents")
+    ///     .service_tier(ServiceTier::Flex)
+    ///     .build();
+    /// ```
+    pub fn service_tier(mut self, tier: ServiceTier) -> Self {
+        self.service_tier = Some(tier);
+        self
+    }
+
+    /// Set the end-user identifier
+    ///
+    /// OpenAI now recommends [`safety_identifier`](Self::safety_identifier)
+    /// and [`prompt_cache_key`](Self::prompt_cache_key) instead.
+    pub fn user(mut self, user: &str) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+
+    /// Set a stable identifier (ideally a hash) of the end user for abuse detection
+    pub fn safety_identifier(mut self, identifier: &str) -> Self {
+        self.safety_identifier = Some(identifier.to_string());
+        self
+    }
+
+    /// Group requests that share a long prefix to improve prompt cache hits
+    pub fn prompt_cache_key(mut self, key: &str) -> Self {
+        self.prompt_cache_key = Some(key.to_string());
+        self
+    }
+
+    /// Run the response in the background instead of holding the connection open
+    ///
+    /// Background responses must be stored, so `store(false)` is rejected.
+    pub fn background(mut self, background: bool) -> Self {
+        self.background = Some(background);
+        self
+    }
+
     /// Add a custom parameter
     ///
     /// # Arguments
@@ -442,9 +1215,31 @@ impl Gpt5RequestBuilder {
         self
     }
 
+    /// Skip any response cache configured on the client for this request
+    ///
+    /// The request is always sent to the API; its response then replaces the
+    /// cached entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5RequestBuilder, Gpt5Model};
+    ///
+    /// let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///     .input("What changed since yesterday?")
+    ///     .bypass_cache(true)
+    ///     .build();
+    /// ```
+    pub fn bypass_cache(mut self, bypass: bool) -> Self {
+        self.bypass_cache = bypass;
+        self
+    }
+
     /// Build the request with validation
     ///
     /// Validates the request parameters and returns the built request.
+    /// Validation issues are logged as warnings; use
+    /// [`build_checked`](Self::build_checked) to get them as an error instead.
     ///
     /// # Examples
     ///
@@ -456,8 +1251,67 @@ impl Gpt5RequestBuilder {
     ///     .build();
     /// ```
     pub fn build(self) -> Gpt5Request {
-        // Pre-check validation
-        self.validate();
+        let (request, issues) = self.build_with_issues();
+        for issue in &issues {
+            tracing::warn!("Gpt5RequestBuilder: {}", issue);
+        }
+        request
+    }
+
+    /// Build the request, failing if any validation rule reports an error
+    ///
+    /// Runs the built-in checks, the model registry's capability checks and
+    /// every rule added with [`rule`](Self::rule). Warnings are logged and
+    /// the request is returned; in [`strict`](Self::strict) mode warnings
+    /// fail the build too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{Gpt5Model, Gpt5RequestBuilder};
+    ///
+    /// let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+    ///     .input("Hi")
+    ///     .top_p(1.5)
+    ///     .build_checked()
+    ///     .unwrap_err();
+    /// assert!(errors.field("top_p").next().is_some());
+    /// ```
+    pub fn build_checked(self) -> Result<Gpt5Request, ValidationErrors> {
+        let strict = self.strict;
+        let (request, issues) = self.build_with_issues();
+
+        let fails = |issue: &ValidationIssue| strict || issue.severity == Severity::Error;
+        if issues.iter().any(fails) {
+            return Err(ValidationErrors::new(issues));
+        }
+        for issue in &issues {
+            tracing::warn!("Gpt5RequestBuilder: {}", issue);
+        }
+        Ok(request)
+    }
+
+    /// Treat validation warnings as errors in [`build_checked`](Self::build_checked)
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Add a custom validation rule run by [`build_checked`](Self::build_checked)
+    ///
+    /// The rule sees the finished request and returns the issues it finds.
+    /// `build` runs rules too but only logs what they report.
+    pub fn rule(
+        mut self,
+        rule: impl Fn(&Gpt5Request) -> Vec<ValidationIssue> + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push(std::sync::Arc::new(rule));
+        self
+    }
+
+    /// Build the request and collect every validation issue
+    fn build_with_issues(self) -> (Gpt5Request, Vec<ValidationIssue>) {
+        let mut issues = self.validate();
 
         let Gpt5RequestBuilder {
             model,
@@ -469,8 +1323,25 @@ impl Gpt5RequestBuilder {
             top_p,
             text,
             instructions,
+            previous_response_id,
+            store,
+            metadata,
+            parallel_tool_calls,
+            max_tool_calls,
+            truncation,
+            service_tier,
+            user,
+            safety_identifier,
+            prompt_cache_key,
+            background,
+            include,
             web_search,
+            file_search,
+            code_interpreter,
+            bypass_cache,
             parameters,
+            strict: _,
+            rules,
         } = self;
 
         let mut tools = tools.unwrap_or_default();
@@ -488,9 +1359,29 @@ impl Gpt5RequestBuilder {
             }
         }
 
+        if let Some(config) = file_search {
+            if !tools.iter().any(|tool| tool.tool_type == "file_search") {
+                tools.push(config.to_tool());
+            }
+        }
+
+        if let Some(container) = code_interpreter {
+            if !tools
+                .iter()
+                .any(|tool| tool.tool_type == "code_interpreter")
+            {
+                let container = serde_json::to_value(&container).unwrap_or_default();
+                tools.push(Tool {
+                    tool_type: "code_interpreter".to_string(),
+                    options: HashMap::from([("container".to_string(), container)]),
+                    ..Default::default()
+                });
+            }
+        }
+
         let tools = if tools.is_empty() { None } else { Some(tools) };
 
-        Gpt5Request {
+        let request = Gpt5Request {
             model: model.as_str().to_string(),
             input,
             reasoning,
@@ -500,34 +1391,67 @@ impl Gpt5RequestBuilder {
             top_p,
             text,
             instructions,
+            previous_response_id,
+            store,
+            metadata,
+            parallel_tool_calls,
+            max_tool_calls,
+            truncation,
+            service_tier,
+            user,
+            safety_identifier,
+            prompt_cache_key,
+            background,
+            include,
             web_search_config,
+            bypass_cache,
             parameters,
+        };
+
+        issues.extend(tool_choice_issues(&request));
+
+        // Check the request against the model's documented capabilities
+        if let Some(info) = ModelInfo::lookup(&request.model) {
+            issues.extend(info.unsupported_issues(&request));
+        }
+        for rule in &rules {
+            issues.extend(rule(&request));
         }
+
+        (request, issues)
     }
 
     /// Validate the request parameters
-    fn validate(&self) {
+    fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
         // Validate input is not empty
         if self.input.trim().is_empty() {
-            tracing::warn!("Gpt5RequestBuilder: Input is empty, this may result in no response");
+            issues.push(ValidationIssue::error("input", "must not be empty"));
         }
 
         // Validate max_output_tokens is reasonable
         if let Some(tokens) = self.max_output_tokens {
             if tokens < 10 {
-                tracing::warn!("Gpt5RequestBuilder: max_output_tokens ({}) is very low, response may be truncated", tokens);
+                issues.push(ValidationIssue::warning(
+                    "max_output_tokens",
+                    format!("{} is very low, response may be truncated", tokens),
+                ));
             } else if tokens > 100000 {
-                tracing::warn!("Gpt5RequestBuilder: max_output_tokens ({}) is very high, this may be expensive", tokens);
+                issues.push(ValidationIssue::warning(
+                    "max_output_tokens",
+                    format!("{} is very high, this may be expensive", tokens),
+                ));
             }
         }
 
         // Validate top_p is in reasonable range
         if let Some(top_p) = self.top_p {
             if !(0.0..=1.0).contains(&top_p) {
-                tracing::warn!(
-                    "Gpt5RequestBuilder: top_p ({}) should be between 0.0 and 1.0",
-                    top_p
-                );
+                issues.push(ValidationIssue::error(
+                    "top_p",
+                    format!("{} should be between 0.0 and 1.0", top_p),
+                ));
             }
         }
 
@@ -537,10 +1461,10 @@ impl Gpt5RequestBuilder {
                 if let Some(ref verbosity) = text.verbosity {
                     match (&reasoning.effort, verbosity) {
                         (ReasoningEffort::High, VerbosityLevel::Low) => {
-                            tracing::warn!("Gpt5RequestBuilder: High reasoning effort with low verbosity may not produce detailed output");
+                            issues.push(ValidationIssue::warning("text.verbosity", "High reasoning effort with low verbosity may not produce detailed output"));
                         }
                         (ReasoningEffort::Low, VerbosityLevel::High) => {
-                            tracing::warn!("Gpt5RequestBuilder: Low reasoning effort with high verbosity may not produce the expected detailed output");
+                            issues.push(ValidationIssue::warning("text.verbosity", "Low reasoning effort with high verbosity may not produce the expected detailed output"));
                         }
                         _ => {} // Good combinations
                     }
@@ -549,33 +1473,217 @@ impl Gpt5RequestBuilder {
         }
 
         if let Some(ref web_search) = self.web_search {
+            if web_search.allowed_domains.len() > 20 {
+                issues.push(ValidationIssue::error(
+                    "web_search.filters.allowed_domains",
+                    format!(
+                        "{} domains exceeds the limit of 20",
+                        web_search.allowed_domains.len()
+                    ),
+                ));
+            }
+            for (index, domain) in web_search.allowed_domains.iter().enumerate() {
+                if domain.contains("://") || domain.contains('/') {
+                    issues.push(ValidationIssue::error(
+                        format!("web_search.filters.allowed_domains[{}]", index),
+                        format!("{} should be a bare domain such as example.com", domain),
+                    ));
+                }
+            }
+            let country = web_search
+                .user_location
+                .as_ref()
+                .and_then(|location| location.country.as_deref());
+            if let Some(country) = country {
+                if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+                    issues.push(ValidationIssue::error(
+                        "web_search.user_location.country",
+                        format!("{} should be a two-letter ISO country code", country),
+                    ));
+                }
+            }
             if let Some(max_results) = web_search.max_results {
                 if max_results == 0 {
-                    tracing::warn!(
-                        "Gpt5RequestBuilder: web_search_max_results is zero; search results will be ignored"
-                    );
+                    issues.push(ValidationIssue::error(
+                        "web_search.max_results",
+                        "is zero; search results will be ignored",
+                    ));
                 }
             }
         }
 
+        if let Some(ref file_search) = self.file_search {
+            if file_search.vector_store_ids.is_empty() {
+                issues.push(ValidationIssue::error(
+                    "file_search.vector_store_ids",
+                    "must name at least one vector store",
+                ));
+            }
+            if let Some(max_results) = file_search.max_num_results {
+                if !(1..=50).contains(&max_results) {
+                    issues.push(ValidationIssue::error(
+                        "file_search.max_num_results",
+                        format!("{} should be between 1 and 50", max_results),
+                    ));
+                }
+            }
+            let threshold = file_search
+                .ranking_options
+                .as_ref()
+                .and_then(|ranking| ranking.score_threshold);
+            if let Some(threshold) = threshold {
+                if !(0.0..=1.0).contains(&threshold) {
+                    issues.push(ValidationIssue::error(
+                        "file_search.ranking_options.score_threshold",
+                        format!("{} should be between 0.0 and 1.0", threshold),
+                    ));
+                }
+            }
+        }
+
+        if let Some(CodeInterpreterContainer::Id(ref id)) = self.code_interpreter {
+            if id.trim().is_empty() {
+                issues.push(ValidationIssue::error(
+                    "code_interpreter.container",
+                    "must not be empty",
+                ));
+            }
+        }
+
+        if let Some(ref metadata) = self.metadata {
+            if metadata.len() > 16 {
+                issues.push(ValidationIssue::error(
+                    "metadata",
+                    format!("{} entries exceeds the limit of 16", metadata.len()),
+                ));
+            }
+            let mut keys: Vec<&String> = metadata.keys().collect();
+            keys.sort();
+            for key in keys {
+                if key.chars().count() > 64 {
+                    issues.push(ValidationIssue::error(
+                        format!("metadata.{}", key),
+                        "key is longer than 64 characters",
+                    ));
+                }
+                if metadata[key].chars().count() > 512 {
+                    issues.push(ValidationIssue::error(
+                        format!("metadata.{}", key),
+                        "value is longer than 512 characters",
+                    ));
+                }
+            }
+        }
+
+        if self.background == Some(true) && self.store == Some(false) {
+            issues.push(ValidationIssue::error(
+                "background",
+                "background responses require store to be enabled",
+            ));
+        }
+
+        if self.max_tool_calls == Some(0) {
+            issues.push(ValidationIssue::warning(
+                "max_tool_calls",
+                "is zero; built-in tools will never be called",
+            ));
+        }
+
         // Validate tools configuration
         if let Some(ref tools) = self.tools {
             if tools.is_empty() {
-                tracing::warn!("Gpt5RequestBuilder: Empty tools array provided");
+                issues.push(ValidationIssue::warning(
+                    "tools",
+                    "Empty tools array provided",
+                ));
             }
         }
 
-        tracing::info!("Gpt5RequestBuilder: Request validation completed");
+        issues
+    }
+}
+
+/// Check that a forced or allowed tool is actually offered
+fn tool_choice_issues(request: &Gpt5Request) -> Vec<ValidationIssue> {
+    let tools = request.tools.as_deref().unwrap_or_default();
+    let offered = |choice: &ToolChoice| match choice {
+        ToolChoice::Function(name) => tools.iter().any(|tool| {
+            tool.tool_type == "function" && tool.name.as_deref() == Some(name.as_str())
+        }),
+        ToolChoice::Hosted(tool_type) => tools.iter().any(|tool| &tool.tool_type == tool_type),
+        _ => true,
+    };
+
+    let mut issues = Vec::new();
+    match &request.tool_choice {
+        Some(ToolChoice::AllowedTools { tools: allowed, .. }) => {
+            for (index, choice) in allowed.iter().enumerate() {
+                if !offered(choice) {
+                    issues.push(ValidationIssue::error(
+                        format!("tool_choice.tools[{}]", index),
+                        "is not one of the request's tools",
+                    ));
+                }
+            }
+        }
+        Some(choice) if !offered(choice) => {
+            issues.push(ValidationIssue::error(
+                "tool_choice",
+                "forces a tool that is not one of the request's tools",
+            ));
+        }
+        Some(ToolChoice::Unknown(value)) => issues.push(ValidationIssue::warning(
+            "tool_choice",
+            format!("{} is not a known tool choice", value),
+        )),
+        _ => {}
     }
+    issues
 }
 
 impl WebSearchConfig {
+    /// The `web_search` tool entry with every API-side option set
     fn to_tool(&self) -> Tool {
+        let mut options = HashMap::new();
+
+        if let Some(size) = &self.search_context_size {
+            options.insert(
+                "search_context_size".to_string(),
+                serde_json::to_value(size).unwrap_or_default(),
+            );
+        }
+        if let Some(location) = &self.user_location {
+            let mut location = serde_json::to_value(location).unwrap_or_default();
+            location["type"] = Value::from("approximate");
+            options.insert("user_location".to_string(), location);
+        }
+        if !self.allowed_domains.is_empty() {
+            options.insert(
+                "filters".to_string(),
+                serde_json::json!({ "allowed_domains": self.allowed_domains }),
+            );
+        }
+
         Tool {
             tool_type: "web_search".to_string(),
-            name: None,
-            description: None,
-            parameters: None,
+            options,
+            ..Default::default()
+        }
+    }
+}
+
+impl FileSearchConfig {
+    /// The `file_search` tool entry
+    fn to_tool(&self) -> Tool {
+        let options = match serde_json::to_value(self) {
+            Ok(Value::Object(fields)) => fields.into_iter().collect(),
+            _ => HashMap::new(),
+        };
+
+        Tool {
+            tool_type: "file_search".to_string(),
+            options,
+            ..Default::default()
         }
     }
 }
@@ -1,7 +1,11 @@
 //! Response structures for GPT-5 API
 
-use crate::enums::{ContentType, FormatType, OutputType, ReasoningEffort, Role, Status};
-use serde::Deserialize;
+use crate::containers::ContainerFile;
+use crate::enums::{
+    ContentType, FormatType, OutputType, ReasoningEffort, Role, ServiceTier, Status, ToolChoice,
+    Truncation,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -38,7 +42,7 @@ use std::collections::HashMap;
 ///     }
 /// }
 /// ```
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Gpt5Response {
     /// Unique identifier assigned by OpenAI for this response instance
     pub id: Option<String>,
@@ -71,26 +75,39 @@ pub struct Gpt5Response {
     /// Aggregated text block supplied alongside the structured output array
     pub text: Option<ResponseText>,
     /// Tool selection strategy that was applied to this response
-    pub tool_choice: Option<String>,
+    pub tool_choice: Option<ToolChoice>,
     /// Raw tool descriptors returned alongside the response
     pub tools: Option<Vec<Value>>,
     /// Top-p sampling value that was used for the request
     pub top_p: Option<f64>,
-    /// Reason the response may have been truncated early, if any
-    pub truncation: Option<String>,
+    /// Truncation strategy that was applied to the input
+    pub truncation: Option<Truncation>,
     /// Token usage and cost accounting details
     pub usage: Option<ResponseUsage>,
     /// Identifier supplied by the caller in the original request
     pub user: Option<String>,
     /// Arbitrary key/value metadata returned by the API
     pub metadata: Option<HashMap<String, Value>>,
+    /// Maximum number of built-in tool calls that was allowed
+    pub max_tool_calls: Option<u32>,
+    /// Processing tier that actually served the request
+    pub service_tier: Option<ServiceTier>,
+    /// End-user safety identifier supplied in the request
+    pub safety_identifier: Option<String>,
+    /// Prompt cache key supplied in the request
+    pub prompt_cache_key: Option<String>,
+    /// Whether the response was run in the background
+    pub background: Option<bool>,
+    /// Set when the response was served from a `ResponseCache` instead of the API
+    #[serde(skip)]
+    pub from_cache: bool,
 }
 
 /// Output content in the response
 ///
 /// Represents individual output items in the GPT-5 response,
 /// which can be messages or function calls.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseOutput {
     /// Discriminator indicating whether this output is a message or tool call
     #[serde(rename = "type")]
@@ -109,13 +126,179 @@ pub struct ResponseOutput {
     pub role: Option<Role>,
     /// Rich content segments that make up the response message
     pub content: Option<Vec<OutputContent>>,
+    /// What a `web_search_call` did (search, open a page, find in a page)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<WebSearchAction>,
+    /// Queries a `file_search_call` ran against the vector stores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queries: Option<Vec<String>>,
+    /// Chunks a `file_search_call` retrieved, when requested with `include: ["file_search_call.results"]`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<FileSearchResult>>,
+    /// Python source a `code_interpreter_call` ran
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Container a `code_interpreter_call` ran in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// Logs, images and files of a `code_interpreter_call`, when requested with `include: ["code_interpreter_call.outputs"]`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<CodeInterpreterOutput>>,
+}
+
+/// Action taken by a `web_search_call` output item
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::WebSearchAction;
+/// use serde_json::json;
+///
+/// let action: WebSearchAction = serde_json::from_value(json!({
+///     "type": "search",
+///     "query": "rust 1.90 release",
+///     "sources": [{"type": "url", "url": "https://blog.rust-lang.org/"}]
+/// }))
+/// .unwrap();
+/// assert_eq!(action.query(), Some("rust 1.90 release"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebSearchAction {
+    /// Ran a search query
+    Search {
+        /// Query sent to the search engine
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        query: Option<String>,
+        /// Sources consulted, when requested with `include: ["web_search_call.action.sources"]`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sources: Option<Vec<WebSearchSource>>,
+    },
+    /// Opened a page from the results
+    OpenPage {
+        /// Page that was opened
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    },
+    /// Searched for text within a page
+    Find {
+        /// Page that was searched
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        /// Text that was looked for
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+    },
+    /// Unknown action (for future compatibility)
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl WebSearchAction {
+    /// The search query, for `Search` actions
+    pub fn query(&self) -> Option<&str> {
+        match self {
+            WebSearchAction::Search { query, .. } => query.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sources consulted, for `Search` actions that report them
+    pub fn sources(&self) -> &[WebSearchSource] {
+        match self {
+            WebSearchAction::Search {
+                sources: Some(sources),
+                ..
+            } => sources,
+            _ => &[],
+        }
+    }
+}
+
+/// A source consulted by a web search
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebSearchSource {
+    /// Kind of source, currently always `url`
+    #[serde(rename = "type")]
+    pub source_type: String,
+    /// Address of the source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A chunk retrieved by a `file_search_call`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileSearchResult {
+    /// Identifier of the file the chunk comes from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+    /// Name of the file the chunk comes from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Relevance score between 0.0 and 1.0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Text of the chunk
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Attributes of the vector store file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, Value>>,
+}
+
+/// Output produced by a `code_interpreter_call`
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::CodeInterpreterOutput;
+/// use serde_json::json;
+///
+/// let output: CodeInterpreterOutput = serde_json::from_value(json!({
+///     "type": "logs",
+///     "logs": "mean revenue: 1204.5\n"
+/// }))
+/// .unwrap();
+/// assert!(matches!(output, CodeInterpreterOutput::Logs { .. }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeInterpreterOutput {
+    /// Text printed by the code
+    Logs {
+        /// Captured stdout and stderr
+        logs: String,
+    },
+    /// Image displayed by the code, e.g. a matplotlib chart
+    Image {
+        /// Address of the image, often a `data:` URL
+        url: String,
+    },
+    /// Files written by the code
+    Files {
+        /// Files created in the container
+        files: Vec<CodeInterpreterFile>,
+    },
+    /// Unknown output (for future compatibility)
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// A file written by a `code_interpreter_call`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterFile {
+    /// Identifier of the file within its container
+    pub file_id: String,
+    /// MIME type such as `image/png` or `text/csv`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
 }
 
 /// Content within an output message
 ///
 /// Represents the actual content of a message output,
 /// typically containing text or other media.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputContent {
     /// Type of content (for example, `output_text`)
     #[serde(rename = "type")]
@@ -123,14 +306,111 @@ pub struct OutputContent {
     /// Textual data provided for text outputs
     pub text: Option<String>,
     /// Optional inline annotations such as citations or tool metadata
-    pub annotations: Option<Vec<Value>>,
+    pub annotations: Option<Vec<Annotation>>,
+}
+
+/// Annotation attached to a span of output text
+///
+/// Indices count characters of the `output_text` the annotation belongs to.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::Annotation;
+/// use serde_json::json;
+///
+/// let annotation: Annotation = serde_json::from_value(json!({
+///     "type": "url_citation",
+///     "start_index": 10,
+///     "end_index": 42,
+///     "url": "https://www.rust-lang.org/",
+///     "title": "Rust Programming Language"
+/// }))
+/// .unwrap();
+/// assert!(matches!(annotation, Annotation::UrlCitation { .. }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Annotation {
+    /// Citation of a web page found by web search
+    UrlCitation {
+        /// First character of the cited text
+        start_index: u32,
+        /// Character after the last one of the cited text
+        end_index: u32,
+        /// Address of the cited page
+        url: String,
+        /// Title of the cited page
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+    /// Citation of an uploaded file found by file search
+    FileCitation {
+        /// Identifier of the cited file
+        file_id: String,
+        /// Name of the cited file
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+        /// Character position in the text the citation applies to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        index: Option<u32>,
+    },
+    /// Citation of a file created in a `code_interpreter` container
+    ContainerFileCitation {
+        /// Container holding the file
+        container_id: String,
+        /// Identifier of the file within its container
+        file_id: String,
+        /// Name of the file, e.g. `revenue.png`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+        /// First character of the text referring to the file
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_index: Option<u32>,
+        /// Character after the last one of the text referring to the file
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end_index: Option<u32>,
+    },
+    /// Unknown annotation (for future compatibility)
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// A web page cited in the response text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Citation {
+    /// Address of the cited page
+    pub url: String,
+    /// Title of the cited page
+    pub title: Option<String>,
+    /// First character of the cited span in `text`
+    pub start_index: u32,
+    /// Character after the last one of the cited span in `text`
+    pub end_index: u32,
+    /// Index of the `output_text` segment (in `Gpt5Response::all_text` order)
+    pub text_index: usize,
+    /// The cited span, when the indices fall inside the text
+    pub cited_text: Option<String>,
+}
+
+/// An uploaded file cited in the response text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCitation {
+    /// Identifier of the cited file
+    pub file_id: String,
+    /// Name of the cited file
+    pub filename: Option<String>,
+    /// Character position in `text` the citation applies to
+    pub index: Option<u32>,
+    /// Index of the `output_text` segment (in `Gpt5Response::all_text` order)
+    pub text_index: usize,
 }
 
 /// Reasoning information in the response
 ///
 /// Contains details about the reasoning process used
 /// to generate the response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseReasoning {
     /// Reported level of reasoning effort spent on the request
     pub effort: Option<ReasoningEffort>,
@@ -141,7 +421,7 @@ pub struct ResponseReasoning {
 /// Text formatting information
 ///
 /// Specifies how the text content should be formatted.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseText {
     /// Formatting metadata describing how the client should render text output
     pub format: Option<ResponseTextFormat>,
@@ -150,7 +430,7 @@ pub struct ResponseText {
 /// Text format specification
 ///
 /// Defines the specific format type for text content.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseTextFormat {
     /// Concrete text format such as Markdown or plain text
     #[serde(rename = "type")]
@@ -160,7 +440,7 @@ pub struct ResponseTextFormat {
 /// Token usage statistics
 ///
 /// Contains information about token usage for the request and response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseUsage {
     /// Count of tokens consumed by input content
     pub input_tokens: u32,
@@ -177,7 +457,7 @@ pub struct ResponseUsage {
 /// Input token details
 ///
 /// Additional information about input token usage.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputTokenDetails {
     /// Number of tokens served from cache rather than newly processed
     pub cached_tokens: Option<u32>,
@@ -186,7 +466,7 @@ pub struct InputTokenDetails {
 /// Output token details
 ///
 /// Additional information about output token usage.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseTokenDetails {
     /// Tokens allocated specifically to reasoning traces
     pub reasoning_tokens: Option<u32>,
@@ -195,7 +475,7 @@ pub struct ResponseTokenDetails {
 /// Error response structure from OpenAI
 ///
 /// Represents error responses from the OpenAI API.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAiError {
     /// Structured error payload returned by the API
     pub error: OpenAiErrorDetails,
@@ -204,7 +484,7 @@ pub struct OpenAiError {
 /// Error details
 ///
 /// Contains specific information about an API error.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAiErrorDetails {
     /// Human-readable explanation of the failure
     pub message: String,
@@ -349,6 +629,285 @@ impl Gpt5Response {
         }
     }
 
+    /// Get web searches run by the hosted `web_search` tool
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Response;
+    /// use serde_json::json;
+    ///
+    /// let response: Gpt5Response = serde_json::from_value(json!({
+    ///     "output": [{
+    ///         "type": "web_search_call",
+    ///         "id": "ws_1",
+    ///         "status": "completed",
+    ///         "action": { "type": "search", "query": "weather in Paris" }
+    ///     }]
+    /// }))
+    /// .unwrap();
+    ///
+    /// for call in response.web_search_calls() {
+    ///     println!("Searched for {:?}", call.action.as_ref().and_then(|a| a.query()));
+    /// }
+    /// ```
+    pub fn web_search_calls(&self) -> Vec<&ResponseOutput> {
+        self.output
+            .iter()
+            .flatten()
+            .filter(|output| output.output_type == OutputType::WebSearchCall)
+            .collect()
+    }
+
+    /// Get the file search calls made by the hosted `file_search` tool
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Response;
+    /// use serde_json::json;
+    ///
+    /// let response: Gpt5Response = serde_json::from_value(json!({
+    ///     "output": [{
+    ///         "type": "file_search_call",
+    ///         "id": "fs_1",
+    ///         "status": "completed",
+    ///         "queries": ["refund window"],
+    ///         "results": [{"file_id": "file-1", "filename": "policy.md", "score": 0.92, "text": "30 days"}]
+    ///     }]
+    /// }))
+    /// .unwrap();
+    ///
+    /// for call in response.file_search_calls() {
+    ///     for result in call.results.iter().flatten() {
+    ///         println!("{:?} scored {:?}", result.filename, result.score);
+    ///     }
+    /// }
+    /// ```
+    pub fn file_search_calls(&self) -> Vec<&ResponseOutput> {
+        self.output
+            .iter()
+            .flatten()
+            .filter(|output| output.output_type == OutputType::FileSearchCall)
+            .collect()
+    }
+
+    /// Get the code interpreter calls made by the hosted `code_interpreter` tool
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::{CodeInterpreterOutput, Gpt5Response};
+    /// use serde_json::json;
+    ///
+    /// let response: Gpt5Response = serde_json::from_value(json!({
+    ///     "output": [{
+    ///         "type": "code_interpreter_call",
+    ///         "id": "ci_1",
+    ///         "status": "completed",
+    ///         "code": "print(2 + 2)",
+    ///         "container_id": "cntr_1",
+    ///         "outputs": [{"type": "logs", "logs": "4\n"}]
+    ///     }]
+    /// }))
+    /// .unwrap();
+    ///
+    /// for call in response.code_interpreter_calls() {
+    ///     println!("Ran:\n{}", call.code.as_deref().unwrap_or_default());
+    ///     for output in call.outputs.iter().flatten() {
+    ///         if let CodeInterpreterOutput::Logs { logs } = output {
+    ///             print!("{}", logs);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn code_interpreter_calls(&self) -> Vec<&ResponseOutput> {
+        self.output
+            .iter()
+            .flatten()
+            .filter(|output| output.output_type == OutputType::CodeInterpreterCall)
+            .collect()
+    }
+
+    /// Get the container files the response refers to, without duplicates
+    ///
+    /// Collects `container_file_citation` annotations and files listed in
+    /// code interpreter outputs. Save them locally with
+    /// [`Gpt5Client::download_container_files`](crate::Gpt5Client::download_container_files).
+    pub fn container_files(&self) -> Vec<ContainerFile> {
+        let mut files: Vec<ContainerFile> = Vec::new();
+        let mut push = |file: ContainerFile| {
+            let existing = files.iter_mut().find(|known| {
+                known.container_id == file.container_id && known.file_id == file.file_id
+            });
+            match existing {
+                Some(known) if known.filename.is_none() => known.filename = file.filename,
+                Some(_) => {}
+                None => files.push(file),
+            }
+        };
+
+        for output in self.output.iter().flatten() {
+            for content in output.content.iter().flatten() {
+                for annotation in content.annotations.iter().flatten() {
+                    if let Annotation::ContainerFileCitation {
+                        container_id,
+                        file_id,
+                        filename,
+                        ..
+                    } = annotation
+                    {
+                        push(ContainerFile {
+                            container_id: container_id.clone(),
+                            file_id: file_id.clone(),
+                            filename: filename.clone(),
+                        });
+                    }
+                }
+            }
+
+            let Some(container_id) = &output.container_id else {
+                continue;
+            };
+            for code_output in output.outputs.iter().flatten() {
+                if let CodeInterpreterOutput::Files { files } = code_output {
+                    for file in files {
+                        push(ContainerFile {
+                            container_id: container_id.clone(),
+                            file_id: file.file_id.clone(),
+                            filename: None,
+                        });
+                    }
+                }
+            }
+        }
+        files
+    }
+
+    /// Get the web pages cited in the response text, in order of appearance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Response;
+    /// use serde_json::json;
+    ///
+    /// let response: Gpt5Response = serde_json::from_value(json!({
+    ///     "output": [{
+    ///         "type": "message",
+    ///         "content": [{
+    ///             "type": "output_text",
+    ///             "text": "Rust 1.90 is out.",
+    ///             "annotations": [{
+    ///                 "type": "url_citation",
+    ///                 "start_index": 0,
+    ///                 "end_index": 17,
+    ///                 "url": "https://blog.rust-lang.org/",
+    ///                 "title": "Rust Blog"
+    ///             }]
+    ///         }]
+    ///     }]
+    /// }))
+    /// .unwrap();
+    ///
+    /// let citations = response.citations();
+    /// assert_eq!(citations[0].url, "https://blog.rust-lang.org/");
+    /// assert_eq!(citations[0].cited_text.as_deref(), Some("Rust 1.90 is out."));
+    /// ```
+    pub fn citations(&self) -> Vec<Citation> {
+        let texts = self
+            .output
+            .iter()
+            .flatten()
+            .filter(|output| output.output_type == OutputType::Message)
+            .flat_map(|output| output.content.iter().flatten())
+            .filter(|content| content.content_type == ContentType::OutputText)
+            .filter(|content| content.text.is_some());
+
+        let mut citations = Vec::new();
+        for (text_index, content) in texts.enumerate() {
+            let text = content.text.as_deref().unwrap_or_default();
+            for annotation in content.annotations.iter().flatten() {
+                if let Annotation::UrlCitation {
+                    start_index,
+                    end_index,
+                    url,
+                    title,
+                } = annotation
+                {
+                    citations.push(Citation {
+                        url: url.clone(),
+                        title: title.clone(),
+                        start_index: *start_index,
+                        end_index: *end_index,
+                        text_index,
+                        cited_text: char_span(text, *start_index, *end_index),
+                    });
+                }
+            }
+        }
+        citations
+    }
+
+    /// Get the uploaded files cited in the response text, in order of appearance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gpt5::Gpt5Response;
+    /// use serde_json::json;
+    ///
+    /// let response: Gpt5Response = serde_json::from_value(json!({
+    ///     "output": [{
+    ///         "type": "message",
+    ///         "content": [{
+    ///             "type": "output_text",
+    ///             "text": "Refunds are accepted for 30 days.",
+    ///             "annotations": [{
+    ///                 "type": "file_citation",
+    ///                 "file_id": "file-1",
+    ///                 "filename": "policy.md",
+    ///                 "index": 33
+    ///             }]
+    ///         }]
+    ///     }]
+    /// }))
+    /// .unwrap();
+    ///
+    /// let citations = response.file_citations();
+    /// assert_eq!(citations[0].filename.as_deref(), Some("policy.md"));
+    /// ```
+    pub fn file_citations(&self) -> Vec<FileCitation> {
+        let texts = self
+            .output
+            .iter()
+            .flatten()
+            .filter(|output| output.output_type == OutputType::Message)
+            .flat_map(|output| output.content.iter().flatten())
+            .filter(|content| content.content_type == ContentType::OutputText)
+            .filter(|content| content.text.is_some());
+
+        let mut citations = Vec::new();
+        for (text_index, content) in texts.enumerate() {
+            for annotation in content.annotations.iter().flatten() {
+                if let Annotation::FileCitation {
+                    file_id,
+                    filename,
+                    index,
+                } = annotation
+                {
+                    citations.push(FileCitation {
+                        file_id: file_id.clone(),
+                        filename: filename.clone(),
+                        index: *index,
+                        text_index,
+                    });
+                }
+            }
+        }
+        citations
+    }
+
     /// Get reasoning token usage
     ///
     /// Returns the number of tokens used for reasoning.
@@ -474,3 +1033,12 @@ impl Gpt5Response {
         self.error.is_some()
     }
 }
+
+/// Characters `start..end` of `text`, if in range
+fn char_span(text: &str, start: u32, end: u32) -> Option<String> {
+    let (start, end) = (start as usize, end as usize);
+    if start > end || end > text.chars().count() {
+        return None;
+    }
+    Some(text.chars().skip(start).take(end - start).collect())
+}
@@ -0,0 +1,97 @@
+//! Secret values that stay out of logs and debug output
+
+use std::fmt;
+
+/// String holding sensitive data such as an API key
+///
+/// The wrapped value is never printed by `Debug`, and `SecretString`
+/// deliberately does not implement `Display`. Call
+/// [`expose_secret`](SecretString::expose_secret) at the point where the raw
+/// value is actually needed. Comparisons take the same time wherever the
+/// values first differ, so they do not leak the secret through timing.
+///
+/// # Examples
+///
+/// ```rust
+/// use gpt5::SecretString;
+///
+/// let key = SecretString::new("sk-live-123");
+/// assert_eq!(format!("{:?}", key), "SecretString([REDACTED])");
+/// assert_eq!(key.expose_secret(), "sk-live-123");
+/// assert!(key == "sk-live-123" && key != "sk-live-124");
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Wrap a secret value
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Access the raw secret value
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true if the secret is an empty string
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString([REDACTED])")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl PartialEq for SecretString {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SecretString {}
+
+impl PartialEq<str> for SecretString {
+    fn eq(&self, other: &str) -> bool {
+        constant_time_eq(&self.0, other)
+    }
+}
+
+impl PartialEq<&str> for SecretString {
+    fn eq(&self, other: &&str) -> bool {
+        constant_time_eq(&self.0, other)
+    }
+}
+
+impl PartialEq<String> for SecretString {
+    fn eq(&self, other: &String) -> bool {
+        constant_time_eq(&self.0, other)
+    }
+}
+
+/// Compare without stopping at the first differing byte; only the lengths leak
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a
+        .bytes()
+        .zip(b.bytes())
+        .fold(0u8, |difference, (a, b)| difference | (a ^ b));
+    std::hint::black_box(difference) == 0
+}
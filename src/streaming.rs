@@ -0,0 +1,239 @@
+//! Server-sent event streaming for `/v1/responses`
+
+use crate::responses::Gpt5Response;
+use serde_json::Value;
+use std::collections::VecDeque;
+
+/// Event received while a response is being streamed
+///
+/// Only the most commonly used events are broken out into variants; every
+/// other event type is surfaced as [`StreamEvent::Other`] with its raw JSON.
+#[derive(Debug, Clone)]
+pub enum StreamEvent {
+    /// The response was created (`response.created`)
+    Created(Gpt5Response),
+    /// A chunk of output text (`response.output_text.delta`)
+    OutputTextDelta {
+        /// Index of the output item the text belongs to
+        output_index: Option<u32>,
+        /// Newly generated text
+        delta: String,
+    },
+    /// An output text block finished (`response.output_text.done`)
+    OutputTextDone {
+        /// Index of the output item the text belongs to
+        output_index: Option<u32>,
+        /// Full text of the block
+        text: String,
+    },
+    /// The response finished successfully (`response.completed`)
+    Completed(Gpt5Response),
+    /// The response stopped early, e.g. at `max_output_tokens` (`response.incomplete`)
+    Incomplete(Gpt5Response),
+    /// The response failed (`response.failed`)
+    Failed(Gpt5Response),
+    /// The stream reported an error (`error`)
+    Error {
+        /// Human-readable error message
+        message: String,
+        /// Machine-readable error code, when provided
+        code: Option<String>,
+    },
+    /// Any other event, with its raw payload
+    Other {
+        /// Event type such as `response.reasoning_summary_text.delta`
+        event_type: String,
+        /// Raw event payload
+        data: Value,
+    },
+}
+
+impl StreamEvent {
+    /// Returns true for events that end the stream
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            StreamEvent::Completed(_)
+                | StreamEvent::Incomplete(_)
+                | StreamEvent::Failed(_)
+                | StreamEvent::Error { .. }
+        )
+    }
+
+    fn from_frame(event_name: Option<&str>, data: &str) -> anyhow::Result<Self> {
+        let data: Value = serde_json::from_str(data)
+            .map_err(|error| anyhow::anyhow!("Invalid stream event payload: {}", error))?;
+        let event_type = data
+            .get("type")
+            .and_then(Value::as_str)
+            .or(event_name)
+            .unwrap_or_default()
+            .to_string();
+
+        let response = || -> anyhow::Result<Gpt5Response> {
+            let response = data.get("response").cloned().unwrap_or(Value::Null);
+            serde_json::from_value(response).map_err(|error| {
+                anyhow::anyhow!("Failed to parse {} payload: {}", event_type, error)
+            })
+        };
+        let output_index = data
+            .get("output_index")
+            .and_then(Value::as_u64)
+            .map(|index| index as u32);
+        let text_field = |name: &str| {
+            data.get(name)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        Ok(match event_type.as_str() {
+            "response.created" => StreamEvent::Created(response()?),
+            "response.output_text.delta" => StreamEvent::OutputTextDelta {
+                output_index,
+                delta: text_field("delta"),
+            },
+            "response.output_text.done" => StreamEvent::OutputTextDone {
+                output_index,
+                text: text_field("text"),
+            },
+            "response.completed" => StreamEvent::Completed(response()?),
+            "response.incomplete" => StreamEvent::Incomplete(response()?),
+            "response.failed" => StreamEvent::Failed(response()?),
+            "error" => StreamEvent::Error {
+                message: text_field("message"),
+                code: data.get("code").and_then(Value::as_str).map(str::to_string),
+            },
+            _ => StreamEvent::Other { event_type, data },
+        })
+    }
+}
+
+/// Stream of events from a streaming `/v1/responses` call
+///
+/// Created by `Gpt5Client::request_stream`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use gpt5::{Gpt5Client, Gpt5Model, Gpt5RequestBuilder, StreamEvent};
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = Gpt5Client::new("sk-...".to_string());
+///     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+///         .input("Tell me a story")
+///         .build();
+///
+///     let mut stream = client.request_stream(request).await?;
+///     while let Some(event) = stream.next().await {
+///         if let StreamEvent::OutputTextDelta { delta, .. } = event? {
+///             print!("{}", delta);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct ResponseStream {
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+    pending: VecDeque<anyhow::Result<StreamEvent>>,
+    finished: bool,
+}
+
+impl ResponseStream {
+    pub(crate) fn new(response: reqwest::Response) -> Self {
+        Self {
+            response,
+            buffer: Vec::new(),
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Wait for the next event, returning `None` once the stream has ended
+    pub async fn next(&mut self) -> Option<anyhow::Result<StreamEvent>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event);
+            }
+            if self.finished {
+                return None;
+            }
+
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => {
+                    self.buffer.extend_from_slice(&chunk);
+                    self.drain_frames();
+                }
+                Ok(None) => {
+                    self.finished = true;
+                    // Flush a final frame that was not followed by a blank line
+                    self.buffer.extend_from_slice(b"\n\n");
+                    self.drain_frames();
+                }
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error.into()));
+                }
+            }
+        }
+    }
+
+    /// Consume the stream and return the final response
+    ///
+    /// Fails if the stream ends without a `response.completed`,
+    /// `response.incomplete` or `response.failed` event, or reports an error.
+    pub async fn final_response(mut self) -> anyhow::Result<Gpt5Response> {
+        while let Some(event) = self.next().await {
+            match event? {
+                StreamEvent::Completed(response)
+                | StreamEvent::Incomplete(response)
+                | StreamEvent::Failed(response) => return Ok(response),
+                StreamEvent::Error { message, .. } => {
+                    return Err(anyhow::anyhow!("GPT-5 stream error: {}", message))
+                }
+                _ => {}
+            }
+        }
+        Err(anyhow::anyhow!(
+            "GPT-5 stream ended without a final response"
+        ))
+    }
+
+    fn drain_frames(&mut self) {
+        while let Some((end, separator_len)) = find_frame_end(&self.buffer) {
+            let frame: Vec<u8> = self.buffer.drain(..end + separator_len).collect();
+            let frame = String::from_utf8_lossy(&frame[..end]);
+
+            let mut event_name = None;
+            let mut data = Vec::new();
+            for line in frame.lines() {
+                if let Some(value) = line.strip_prefix("event:") {
+                    event_name = Some(value.trim().to_string());
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data.push(value.strip_prefix(' ').unwrap_or(value));
+                }
+            }
+
+            let data = data.join("\n");
+            if data.is_empty() || data == "[DONE]" {
+                continue;
+            }
+            self.pending
+                .push_back(StreamEvent::from_frame(event_name.as_deref(), &data));
+        }
+    }
+}
+
+/// Position and length of the first blank-line frame separator
+fn find_frame_end(buffer: &[u8]) -> Option<(usize, usize)> {
+    let lf = buffer.windows(2).position(|window| window == b"\n\n");
+    let crlf = buffer.windows(4).position(|window| window == b"\r\n\r\n");
+    match (lf, crlf) {
+        (Some(lf), Some(crlf)) if crlf < lf => Some((crlf, 4)),
+        (Some(lf), _) => Some((lf, 2)),
+        (None, Some(crlf)) => Some((crlf, 4)),
+        (None, None) => None,
+    }
+}
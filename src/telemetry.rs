@@ -0,0 +1,102 @@
+//! Tracing spans following the OpenTelemetry GenAI semantic conventions
+//!
+//! Every call to `Gpt5Client::request` runs inside a `gen_ai.request` span.
+//! Field names match the OpenTelemetry attribute names, so exporters such as
+//! `tracing-opentelemetry` forward them unchanged to Jaeger, Tempo and friends.
+//! Request and response bodies are never attached to the span; they are only
+//! emitted as `TRACE` level events.
+
+use crate::client::ApiError;
+use crate::requests::Gpt5Request;
+use crate::responses::Gpt5Response;
+use std::time::Duration;
+use tracing::field::Empty;
+use tracing::Span;
+
+/// Create the span describing a single Responses API call
+pub(crate) fn request_span(request: &Gpt5Request) -> Span {
+    let span = tracing::info_span!(
+        "gen_ai.request",
+        otel.name = %format!("chat {}", request.model),
+        otel.kind = "client",
+        otel.status_code = Empty,
+        gen_ai.system = "openai",
+        gen_ai.operation.name = "chat",
+        gen_ai.request.model = %request.model,
+        gen_ai.request.max_tokens = Empty,
+        gen_ai.request.top_p = Empty,
+        gen_ai.request.reasoning_effort = Empty,
+        gen_ai.response.id = Empty,
+        gen_ai.response.model = Empty,
+        gen_ai.response.status = Empty,
+        gen_ai.response.from_cache = Empty,
+        gen_ai.usage.input_tokens = Empty,
+        gen_ai.usage.output_tokens = Empty,
+        gen_ai.usage.reasoning_tokens = Empty,
+        http.response.status_code = Empty,
+        error.type = Empty,
+        latency_ms = Empty,
+    );
+
+    if let Some(tokens) = request.max_output_tokens {
+        span.record("gen_ai.request.max_tokens", tokens);
+    }
+    if let Some(top_p) = request.top_p {
+        span.record("gen_ai.request.top_p", top_p);
+    }
+    if let Some(reasoning) = &request.reasoning {
+        if let Ok(serde_json::Value::String(effort)) = serde_json::to_value(&reasoning.effort) {
+            span.record("gen_ai.request.reasoning_effort", effort.as_str());
+        }
+    }
+
+    span
+}
+
+/// Record response metadata and token usage on the span
+pub(crate) fn record_response(span: &Span, response: &Gpt5Response) {
+    if let Some(id) = &response.id {
+        span.record("gen_ai.response.id", id.as_str());
+    }
+    if let Some(model) = &response.model {
+        span.record("gen_ai.response.model", model.as_str());
+    }
+    if let Some(Ok(serde_json::Value::String(status))) =
+        response.status.as_ref().map(serde_json::to_value)
+    {
+        span.record("gen_ai.response.status", status.as_str());
+    }
+    if let Some(usage) = &response.usage {
+        span.record("gen_ai.usage.input_tokens", usage.input_tokens);
+        span.record("gen_ai.usage.output_tokens", usage.output_tokens);
+    }
+    if let Some(tokens) = response.reasoning_tokens() {
+        span.record("gen_ai.usage.reasoning_tokens", tokens);
+    }
+    span.record("gen_ai.response.from_cache", response.from_cache);
+    span.record("otel.status_code", "OK");
+}
+
+/// Record a failed call on the span
+pub(crate) fn record_error(span: &Span, error: &anyhow::Error) {
+    span.record("otel.status_code", "ERROR");
+
+    if let Some(api_error) = error.downcast_ref::<ApiError>() {
+        span.record("http.response.status_code", api_error.status);
+        span.record("error.type", api_error.error_type());
+        return;
+    }
+
+    let error_type = match error.downcast_ref::<reqwest::Error>() {
+        Some(error) if error.is_timeout() => "timeout",
+        Some(error) if error.is_connect() => "connect",
+        Some(_) => "transport",
+        None => "_OTHER",
+    };
+    span.record("error.type", error_type);
+}
+
+/// Record the end-to-end latency of the call
+pub(crate) fn record_latency(span: &Span, elapsed: Duration) {
+    span.record("latency_ms", elapsed.as_millis() as u64);
+}
@@ -0,0 +1,135 @@
+//! Structured request validation
+//!
+//! `Gpt5RequestBuilder::build_checked` runs the built-in rules, the model
+//! registry's capability checks and any custom rules, and returns every
+//! problem found as a [`ValidationIssue`] naming the offending field.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gpt5::{Gpt5Model, Gpt5RequestBuilder, Severity, ValidationIssue};
+//!
+//! let result = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+//!     .input("Summarize the report")
+//!     .rule(|request| {
+//!         let mut issues = Vec::new();
+//!         if request.instructions.is_none() {
+//!             issues.push(ValidationIssue::error("instructions", "house style requires instructions"));
+//!         }
+//!         issues
+//!     })
+//!     .build_checked();
+//!
+//! let errors = result.unwrap_err();
+//! assert_eq!(errors.issues()[0].field, "instructions");
+//! assert_eq!(errors.issues()[0].severity, Severity::Error);
+//! ```
+
+use crate::requests::Gpt5Request;
+use std::fmt;
+use std::sync::Arc;
+
+/// How serious a validation issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The API would reject the request or ignore the setting
+    Error,
+    /// The request is valid but probably not what was intended
+    Warning,
+}
+
+/// A single problem found in a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// Path of the offending field, e.g. `top_p` or `tools[1].type`
+    pub field: String,
+    /// What is wrong with it
+    pub message: String,
+    /// Whether the issue fails `build_checked`
+    pub severity: Severity,
+}
+
+impl ValidationIssue {
+    /// An issue that fails `build_checked`
+    pub fn error(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// An issue that is only logged, unless the builder is strict
+    pub fn warning(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+            severity: Severity::Warning,
+        }
+    }
+
+    /// Whether this issue is an error
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Issues that stopped `build_checked` from returning a request
+///
+/// Warnings found alongside the errors are included so callers see the
+/// full picture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationErrors {
+    pub(crate) fn new(issues: Vec<ValidationIssue>) -> Self {
+        Self { issues }
+    }
+
+    /// Every issue, in the order the rules reported them
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Issues with [`Severity::Error`]
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| issue.is_error())
+    }
+
+    /// Issues with [`Severity::Warning`]
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| !issue.is_error())
+    }
+
+    /// Issues reported for `field`
+    pub fn field(&self, field: &str) -> impl Iterator<Item = &ValidationIssue> {
+        let field = field.to_string();
+        self.issues.iter().filter(move |issue| issue.field == field)
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid GPT-5 request: ")?;
+        for (index, issue) in self.issues.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Custom check run against every request built with `build_checked`
+pub(crate) type ValidationRule = Arc<dyn Fn(&Gpt5Request) -> Vec<ValidationIssue> + Send + Sync>;
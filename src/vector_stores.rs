@@ -0,0 +1,409 @@
+//! Vector stores searched by the hosted `file_search` tool (`/v1/vector_stores`)
+//!
+//! Files are uploaded with the Files API (`purpose: "assistants"`), attached
+//! to a vector store, chunked and embedded by OpenAI, and then searched by
+//! requests built with [`Gpt5RequestBuilder::file_search`](crate::Gpt5RequestBuilder::file_search).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use gpt5::{CreateVectorStore, Gpt5Client, Gpt5Model, Gpt5RequestBuilder};
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let client = Gpt5Client::from_env()?;
+//!     let stores = client.vector_stores();
+//!
+//!     let store = stores
+//!         .create(CreateVectorStore::new("handbook").expires_after_days(7))
+//!         .await?;
+//!     stores.attach_file(&store.id, "file-abc123").await?;
+//!     let store = stores.wait_until_ready(&store.id, Duration::from_secs(120)).await?;
+//!     println!("{} files indexed", store.file_counts.completed);
+//!
+//!     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+//!         .input("What is our refund window?")
+//!         .file_search([store.id.as_str()])
+//!         .build();
+//!     println!("{:?}", client.request(request).await?.text());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::client::{encode_component, Gpt5Client};
+use crate::enums::{VectorStoreFileStatus, VectorStoreStatus};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Longest pause between two status checks in `wait_until_ready`
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Vector store operations, borrowed from a `Gpt5Client`
+///
+/// Created with [`Gpt5Client::vector_stores`].
+#[derive(Debug, Clone, Copy)]
+pub struct VectorStores<'a> {
+    client: &'a Gpt5Client,
+}
+
+/// Settings for a new vector store
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateVectorStore {
+    /// Display name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Uploaded files to attach right away
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub file_ids: Vec<String>,
+    /// Delete the store after this long without use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<ExpiresAfter>,
+    /// Up to 16 key/value pairs attached to the store
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+/// Expiration policy of a vector store
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpiresAfter {
+    /// Timestamp the countdown starts from, currently always `last_active_at`
+    pub anchor: String,
+    /// Days after the anchor before the store expires
+    pub days: u32,
+}
+
+/// A vector store as returned by the API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStore {
+    /// Identifier passed to `file_search`, e.g. `vs_abc123`
+    pub id: String,
+    /// Always `vector_store`
+    #[serde(default)]
+    pub object: String,
+    /// Unix timestamp of when the store was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// Display name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Storage used by the store's files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_bytes: Option<u64>,
+    /// Files per ingestion state
+    #[serde(default)]
+    pub file_counts: FileCounts,
+    /// Whether the store is ready to be searched
+    pub status: VectorStoreStatus,
+    /// Expiration policy, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<ExpiresAfter>,
+    /// Unix timestamp of when the store will expire
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Unix timestamp of when the store was last searched or changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_active_at: Option<u64>,
+    /// Key/value pairs attached to the store
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Number of files in a vector store per ingestion state
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCounts {
+    /// Files still being processed
+    #[serde(default)]
+    pub in_progress: u32,
+    /// Files ready to be searched
+    #[serde(default)]
+    pub completed: u32,
+    /// Files that could not be processed
+    #[serde(default)]
+    pub failed: u32,
+    /// Files whose processing was cancelled
+    #[serde(default)]
+    pub cancelled: u32,
+    /// All files in the store
+    #[serde(default)]
+    pub total: u32,
+}
+
+/// A file attached to a vector store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreFile {
+    /// Identifier of the uploaded file
+    pub id: String,
+    /// Always `vector_store.file`
+    #[serde(default)]
+    pub object: String,
+    /// Unix timestamp of when the file was attached
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// Store the file belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector_store_id: Option<String>,
+    /// Storage used by the file's chunks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_bytes: Option<u64>,
+    /// Ingestion state
+    pub status: VectorStoreFileStatus,
+    /// Why ingestion failed, for `Failed` files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<VectorStoreFileError>,
+    /// Attributes matched by `file_search` filters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, Value>>,
+}
+
+/// Ingestion error of a vector store file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreFileError {
+    /// Error code such as `unsupported_file` or `invalid_file`
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+}
+
+/// One page of a list endpoint
+#[derive(Deserialize)]
+struct Page<T> {
+    data: Vec<T>,
+    #[serde(default)]
+    has_more: bool,
+    last_id: Option<String>,
+}
+
+/// Body of a delete endpoint
+#[derive(Deserialize)]
+struct Deleted {
+    #[serde(default)]
+    deleted: bool,
+}
+
+impl CreateVectorStore {
+    /// Settings for a store with the given display name
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Attach already uploaded files when the store is created
+    pub fn file_ids<I, S>(mut self, file_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.file_ids.extend(file_ids.into_iter().map(Into::into));
+        self
+    }
+
+    /// Delete the store after `days` days without use
+    pub fn expires_after_days(mut self, days: u32) -> Self {
+        self.expires_after = Some(ExpiresAfter {
+            anchor: "last_active_at".to_string(),
+            days,
+        });
+        self
+    }
+
+    /// Attach a metadata key/value pair to the store
+    pub fn metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+impl VectorStore {
+    /// Whether every file has been processed and the store can be searched
+    pub fn is_ready(&self) -> bool {
+        self.status == VectorStoreStatus::Completed
+    }
+}
+
+impl Gpt5Client {
+    /// Manage the vector stores searched by the `file_search` tool
+    pub fn vector_stores(&self) -> VectorStores<'_> {
+        VectorStores { client: self }
+    }
+}
+
+impl VectorStores<'_> {
+    /// Create a vector store
+    pub async fn create(&self, store: CreateVectorStore) -> anyhow::Result<VectorStore> {
+        let body = serde_json::to_value(&store)?;
+        self.client
+            .send_json(Method::POST, "vector_stores", Some(&body))
+            .await
+    }
+
+    /// Fetch a vector store by id
+    pub async fn retrieve(&self, id: &str) -> anyhow::Result<VectorStore> {
+        self.client
+            .get_json(&format!("vector_stores/{}", encode_component(id)))
+            .await
+    }
+
+    /// List every vector store, following pagination
+    pub async fn list(&self) -> anyhow::Result<Vec<VectorStore>> {
+        self.list_all("vector_stores").await
+    }
+
+    /// Delete a vector store; its files stay uploaded
+    ///
+    /// Returns whether the API reported the store as deleted.
+    pub async fn delete(&self, id: &str) -> anyhow::Result<bool> {
+        let deleted: Deleted = self
+            .client
+            .send_json(
+                Method::DELETE,
+                &format!("vector_stores/{}", encode_component(id)),
+                None,
+            )
+            .await?;
+        Ok(deleted.deleted)
+    }
+
+    /// Attach an uploaded file to a vector store
+    ///
+    /// Ingestion runs in the background; see [`wait_until_ready`](Self::wait_until_ready).
+    pub async fn attach_file(
+        &self,
+        store_id: &str,
+        file_id: &str,
+    ) -> anyhow::Result<VectorStoreFile> {
+        self.attach_file_with_attributes(store_id, file_id, HashMap::new())
+            .await
+    }
+
+    /// Attach an uploaded file with attributes that `file_search` filters can match
+    pub async fn attach_file_with_attributes(
+        &self,
+        store_id: &str,
+        file_id: &str,
+        attributes: HashMap<String, Value>,
+    ) -> anyhow::Result<VectorStoreFile> {
+        let mut body = json!({ "file_id": file_id });
+        if !attributes.is_empty() {
+            body["attributes"] = serde_json::to_value(attributes)?;
+        }
+        self.client
+            .send_json(
+                Method::POST,
+                &format!("vector_stores/{}/files", encode_component(store_id)),
+                Some(&body),
+            )
+            .await
+    }
+
+    /// Fetch one file of a vector store, including its ingestion status
+    pub async fn retrieve_file(
+        &self,
+        store_id: &str,
+        file_id: &str,
+    ) -> anyhow::Result<VectorStoreFile> {
+        self.client
+            .get_json(&format!(
+                "vector_stores/{}/files/{}",
+                encode_component(store_id),
+                encode_component(file_id)
+            ))
+            .await
+    }
+
+    /// List every file of a vector store, following pagination
+    pub async fn list_files(&self, store_id: &str) -> anyhow::Result<Vec<VectorStoreFile>> {
+        self.list_all(&format!(
+            "vector_stores/{}/files",
+            encode_component(store_id)
+        ))
+        .await
+    }
+
+    /// Remove a file from a vector store without deleting the upload
+    ///
+    /// Returns whether the API reported the file as removed.
+    pub async fn detach_file(&self, store_id: &str, file_id: &str) -> anyhow::Result<bool> {
+        let deleted: Deleted = self
+            .client
+            .send_json(
+                Method::DELETE,
+                &format!(
+                    "vector_stores/{}/files/{}",
+                    encode_component(store_id),
+                    encode_component(file_id)
+                ),
+                None,
+            )
+            .await?;
+        Ok(deleted.deleted)
+    }
+
+    /// Poll a vector store until its files have been processed
+    ///
+    /// Checks back with a growing delay (up to five seconds) and returns the
+    /// store once it is no longer `in_progress`. Files that failed are
+    /// counted in `file_counts.failed`; list them with
+    /// [`list_files`](Self::list_files) to read their errors. Fails if the
+    /// store has expired or is still processing after `timeout`.
+    pub async fn wait_until_ready(
+        &self,
+        id: &str,
+        timeout: Duration,
+    ) -> anyhow::Result<VectorStore> {
+        let started = Instant::now();
+        let mut interval = Duration::from_millis(250);
+
+        loop {
+            let store = self.retrieve(id).await?;
+            match store.status {
+                VectorStoreStatus::InProgress => {}
+                VectorStoreStatus::Expired => {
+                    return Err(anyhow::anyhow!("Vector store {} has expired", id));
+                }
+                _ => return Ok(store),
+            }
+
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if remaining.is_zero() {
+                return Err(anyhow::anyhow!(
+                    "Vector store {} is still processing after {:?} ({} of {} files done)",
+                    id,
+                    timeout,
+                    store
+                        .file_counts
+                        .total
+                        .saturating_sub(store.file_counts.in_progress),
+                    store.file_counts.total
+                ));
+            }
+            tokio::time::sleep(interval.min(remaining)).await;
+            interval = (interval * 2).min(MAX_POLL_INTERVAL);
+        }
+    }
+
+    /// Collect every page of a list endpoint
+    async fn list_all<T: serde::de::DeserializeOwned>(&self, path: &str) -> anyhow::Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let page_path = match &after {
+                Some(cursor) => format!("{}?limit=100&after={}", path, encode_component(cursor)),
+                None => format!("{}?limit=100", path),
+            };
+            let page: Page<T> = self.client.get_json(&page_path).await?;
+            items.extend(page.data);
+
+            match page.last_id {
+                Some(last_id) if page.has_more => after = Some(last_id),
+                _ => return Ok(items),
+            }
+        }
+    }
+}
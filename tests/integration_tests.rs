@@ -3,12 +3,23 @@
 //! These tests verify the complete functionality of the library including
 //! serialization, deserialization, and API interactions.
 
+use gpt5::middleware::{
+    BoxFuture, HeadersMiddleware, MetadataMiddleware, Middleware, MiddlewareRequest, Next,
+};
+use gpt5::mock::{MockReply, MockScript, MockServer};
 use gpt5::{
-    ContentType, FormatType, Gpt5Client, Gpt5Model, Gpt5RequestBuilder, OutputType,
-    ReasoningEffort, Role, Status, Tool, VerbosityLevel,
+    AllowedToolsMode, Annotation, AttributeFilter, AzureConfig, CacheStats, CodeInterpreterOutput,
+    ContainerFile, ContentType, CreateVectorStore, FormatType, Gpt5Client, Gpt5Model,
+    Gpt5RequestBuilder, Gpt5Response, ModelObject, ModelPolicy, OutputType, Ranker, RankingOptions,
+    ReasoningEffort, RedactionPolicy, RequestOptions, ResponseCache, Role, SearchContextSize,
+    ServiceTier, Status, StreamEvent, Tool, ToolChoice, Truncation, UserLocation,
+    VectorStoreFileStatus, VectorStoreStatus, VerbosityLevel, WebSearchAction,
 };
 use reqwest::Client as HttpClient;
 use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// Test Gpt5Model enum functionality
 #[test]
@@ -19,6 +30,40 @@ fn test_gpt5_model_serialization() {
 
     let custom = Gpt5Model::Custom("gpt-5-custom".to_string());
     assert_eq!(custom.as_str(), "gpt-5-custom");
+
+    // Parsing and display round-trip; unknown names become `Custom`
+    for model in Gpt5Model::KNOWN {
+        assert_eq!(model.to_string().parse::<Gpt5Model>().unwrap(), *model);
+    }
+    assert!(matches!("gpt-5-mini".parse(), Ok(Gpt5Model::Gpt5Mini)));
+    assert_eq!("gpt-5-custom".parse::<Gpt5Model>().unwrap(), custom);
+    assert_eq!(custom.to_string(), "gpt-5-custom");
+
+    // Equality and hashing go by name
+    assert_eq!(
+        Gpt5Model::Custom("gpt-5-nano".to_string()),
+        Gpt5Model::Gpt5Nano
+    );
+    assert_ne!(Gpt5Model::Gpt5, Gpt5Model::Gpt5Mini);
+    let set: std::collections::HashSet<Gpt5Model> = [
+        Gpt5Model::Gpt5Nano,
+        Gpt5Model::Custom("gpt-5-nano".to_string()),
+        custom.clone(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+
+    #[derive(serde::Deserialize)]
+    struct Config {
+        model: Gpt5Model,
+    }
+    let config: Config = serde_json::from_str(r#"{"model": "gpt-5-mini"}"#).unwrap();
+    assert!(matches!(config.model, Gpt5Model::Gpt5Mini));
+    assert_eq!(
+        serde_json::to_value(&custom).unwrap(),
+        json!("gpt-5-custom")
+    );
 }
 
 /// Test ReasoningEffort enum serialization and deserialization
@@ -199,6 +244,22 @@ fn test_gpt5_request_builder_web_search_disabled() {
         .map(|tools| tools.into_iter().all(|tool| tool.tool_type != "web_search"))
         .unwrap_or(true));
     assert!(request.web_search_config.is_none());
+
+    // Turning sources off never turns web search on
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("No search")
+        .web_search_sources(false)
+        .build();
+    assert!(request.tools.is_none());
+    assert!(request.include.is_none());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("Search without sources")
+        .web_search_sources(true)
+        .web_search_sources(false)
+        .build();
+    assert_eq!(request.tools.unwrap()[0].tool_type, "web_search");
+    assert!(request.include.is_none());
 }
 
 /// Test Gpt5RequestBuilder with all parameters
@@ -214,6 +275,7 @@ fn test_gpt5_request_builder_complete() {
                 "location": {"type": "string"}
             }
         })),
+        ..Default::default()
     };
 
     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
@@ -235,7 +297,7 @@ fn test_gpt5_request_builder_complete() {
     );
     assert!(request.reasoning.is_some());
     assert!(request.tools.is_some());
-    assert_eq!(request.tool_choice, Some("auto".to_string()));
+    assert_eq!(request.tool_choice, Some(ToolChoice::Auto));
     assert_eq!(request.max_output_tokens, Some(1000));
     assert_eq!(request.top_p, Some(0.9));
     assert!(request.text.is_some());
@@ -267,6 +329,7 @@ fn test_tool_creation() {
                 "param1": {"type": "string"}
             }
         })),
+        ..Default::default()
     };
 
     assert_eq!(tool.tool_type, "function");
@@ -521,6 +584,7 @@ fn test_complex_tool_definition() {
             },
             "required": ["data", "method"]
         })),
+        ..Default::default()
     };
 
     assert_eq!(complex_tool.name.as_deref(), Some("analyze_data"));
@@ -539,6 +603,7 @@ fn test_multiple_tools() {
         name: Some("tool1".to_string()),
         description: Some("First tool".to_string()),
         parameters: Some(json!({})),
+        ..Default::default()
     };
 
     let tool2 = Tool {
@@ -546,6 +611,7 @@ fn test_multiple_tools() {
         name: Some("tool2".to_string()),
         description: Some("Second tool".to_string()),
         parameters: Some(json!({})),
+        ..Default::default()
     };
 
     let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
@@ -556,5 +622,2164 @@ fn test_multiple_tools() {
 
     assert!(request.tools.is_some());
     assert_eq!(request.tools.unwrap().len(), 2);
-    assert_eq!(request.tool_choice, Some("auto".to_string()));
+    assert_eq!(request.tool_choice, Some(ToolChoice::Auto));
+}
+
+/// Test a text reply served by the mock Responses API
+#[tokio::test]
+async fn test_mock_server_text_reply() {
+    let server = MockServer::start(MockScript::new(vec![MockReply::text("Hello from mock")]))
+        .await
+        .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let text = client
+        .simple(Gpt5Model::Gpt5Nano, "Hi there")
+        .await
+        .unwrap();
+    assert_eq!(text, "Hello from mock");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/v1/responses");
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer test-api-key")
+    );
+    assert_eq!(requests[0].body["model"], "gpt-5-nano");
+    assert_eq!(requests[0].body["input"], "Hi there");
+}
+
+/// Test scripted function calls, errors and latency from the mock server
+#[tokio::test]
+async fn test_mock_server_scripted_sequence() {
+    let script = MockScript::from_json(
+        r#"{
+            "replies": [
+                { "function_call": { "name": "get_weather", "arguments": { "location": "Boston" } } },
+                { "error": { "status": 429 }, "latency_ms": 50 }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let server = MockServer::start(script).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("Weather in Boston?")
+        .build();
+    let response = client.request(request).await.unwrap();
+    let calls = response.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].name.as_deref(), Some("get_weather"));
+    assert_eq!(
+        calls[0].arguments.as_deref(),
+        Some("{\"location\":\"Boston\"}")
+    );
+
+    let started = Instant::now();
+    let error = client
+        .simple(Gpt5Model::Gpt5Nano, "Again")
+        .await
+        .unwrap_err();
+    assert!(started.elapsed() >= Duration::from_millis(50));
+    assert!(error.to_string().contains("429"));
+
+    // The last reply repeats once the script is exhausted
+    let error = client
+        .simple(Gpt5Model::Gpt5Nano, "Again")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Rate limit"));
+}
+
+/// Test that mock scripts load from JSON files and anything else is rejected
+#[test]
+fn test_mock_script_from_path() {
+    let dir = std::env::temp_dir().join(format!("gpt5-mock-script-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let json = dir.join("replies.json");
+    std::fs::write(
+        &json,
+        r#"{"replies": [{"text": "Hello"}], "models": ["gpt-5"]}"#,
+    )
+    .unwrap();
+    let script = MockScript::from_path(&json).unwrap();
+    assert_eq!(script.replies.len(), 1);
+    assert_eq!(script.models, vec!["gpt-5".to_string()]);
+
+    let yaml = dir.join("replies.yaml");
+    std::fs::write(&yaml, "replies:\n  - text: Hello\n").unwrap();
+    let error = MockScript::from_path(&yaml).unwrap_err();
+    assert!(error.to_string().contains("expected JSON"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Test that the mock server streams server-sent events
+#[tokio::test]
+async fn test_mock_server_streaming() {
+    let script = MockScript::from_json(r#"{"replies": [{"text": "one two three"}]}"#).unwrap();
+    let server = MockServer::start(script).await.unwrap();
+
+    let body = HttpClient::new()
+        .post(format!("{}/v1/responses", server.base_url()))
+        .json(&json!({ "model": "gpt-5-nano", "input": "Count", "stream": true }))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    assert!(body.starts_with("event: response.created"));
+    assert_eq!(body.matches("event: response.output_text.delta").count(), 3);
+    assert!(body.contains("event: response.completed"));
+    assert!(body.contains("\"delta\":\"two \""));
+}
+
+/// Middleware that records the order in which layers run
+struct RecordingMiddleware {
+    label: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for RecordingMiddleware {
+    fn handle<'a>(
+        &'a self,
+        req: MiddlewareRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+        Box::pin(async move {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} before", self.label));
+            let result = next.run(req).await;
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} after", self.label));
+            result
+        })
+    }
+}
+
+/// Middleware that answers without calling the API
+struct ShortCircuitMiddleware;
+
+impl Middleware for ShortCircuitMiddleware {
+    fn handle<'a>(
+        &'a self,
+        _req: MiddlewareRequest,
+        _next: Next<'a>,
+    ) -> BoxFuture<'a, anyhow::Result<Gpt5Response>> {
+        Box::pin(async move {
+            Ok(serde_json::from_value(json!({
+                "status": "completed",
+                "output": [{
+                    "type": "message",
+                    "content": [{ "type": "output_text", "text": "short-circuited" }]
+                }]
+            }))?)
+        })
+    }
+}
+
+/// Test that middleware layers run in order and can modify the outgoing request
+#[tokio::test]
+async fn test_middleware_chain_order_and_headers() {
+    let server = MockServer::start(MockScript::new(vec![MockReply::text("ok")]))
+        .await
+        .unwrap();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_middleware(RecordingMiddleware {
+            label: "outer",
+            log: Arc::clone(&log),
+        })
+        .with_middleware(RecordingMiddleware {
+            label: "inner",
+            log: Arc::clone(&log),
+        })
+        .with_middleware(HeadersMiddleware::new().header("x-team", "search").unwrap())
+        .with_middleware(MetadataMiddleware::new().entry("service", "tests"));
+
+    let text = client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap();
+    assert_eq!(text, "ok");
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["outer before", "inner before", "inner after", "outer after"]
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("x-team"), Some("search"));
+    assert_eq!(requests[0].body["metadata"]["service"], "tests");
+}
+
+/// Test that the metadata layer merges into metadata set on the builder
+#[tokio::test]
+async fn test_metadata_middleware_with_typed_metadata() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_middleware(
+            MetadataMiddleware::new()
+                .entry("service", "tests")
+                .entry("team", "ignored"),
+        );
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .metadata("team", "search")
+        .build();
+    client.request(request).await.unwrap();
+
+    assert_eq!(
+        server.requests()[0].body["metadata"],
+        json!({"team": "search", "service": "tests"})
+    );
+}
+
+/// Test that a middleware layer can short-circuit the HTTP call
+#[tokio::test]
+async fn test_middleware_short_circuit() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_middleware(ShortCircuitMiddleware);
+
+    let text = client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap();
+    assert_eq!(text, "short-circuited");
+    assert!(server.requests().is_empty());
+}
+
+/// Test that identical requests are served from the in-memory cache
+#[tokio::test]
+async fn test_response_cache_hits_and_bypass() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("first"),
+        MockReply::text("second"),
+    ]))
+    .await
+    .unwrap();
+    let cache = ResponseCache::in_memory(16);
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_cache(cache.clone());
+    let request = || {
+        Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Cached prompt")
+            .build()
+    };
+
+    let fresh = client.request(request()).await.unwrap();
+    assert!(!fresh.from_cache);
+    assert_eq!(fresh.text().as_deref(), Some("first"));
+
+    let cached = client.request(request()).await.unwrap();
+    assert!(cached.from_cache);
+    assert_eq!(cached.id, fresh.id);
+    assert_eq!(cached.text().as_deref(), Some("first"));
+    assert_eq!(server.requests().len(), 1);
+
+    let bypassed = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Cached prompt")
+        .bypass_cache(true)
+        .build();
+    let refreshed = client.request(bypassed).await.unwrap();
+    assert!(!refreshed.from_cache);
+    assert_eq!(refreshed.text().as_deref(), Some("second"));
+
+    // The bypassed call refreshed the cached entry
+    let cached = client.request(request()).await.unwrap();
+    assert_eq!(cached.text().as_deref(), Some("second"));
+    assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 1 });
+}
+
+/// Test that calls with per-request API keys never share cached responses
+#[tokio::test]
+async fn test_response_cache_skips_api_key_overrides() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("client"),
+        MockReply::text("tenant a"),
+        MockReply::text("tenant b"),
+    ]))
+    .await
+    .unwrap();
+    let cache = ResponseCache::in_memory(16);
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_cache(cache.clone());
+    let request = || {
+        Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Shared prompt")
+            .build()
+    };
+
+    // Warm the cache with the client's own key
+    client.request(request()).await.unwrap();
+
+    let tenant_a = client
+        .request_with_options(request(), RequestOptions::new().api_key("sk-tenant-a"))
+        .await
+        .unwrap();
+    let tenant_b = client
+        .request_with_options(request(), RequestOptions::new().api_key("sk-tenant-b"))
+        .await
+        .unwrap();
+    assert!(!tenant_a.from_cache);
+    assert_eq!(tenant_a.text().as_deref(), Some("tenant a"));
+    assert!(!tenant_b.from_cache);
+    assert_eq!(tenant_b.text().as_deref(), Some("tenant b"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(
+        requests[2].header("authorization"),
+        Some("Bearer sk-tenant-b")
+    );
+
+    // Overridden calls neither read nor refresh the shared entry
+    let cached = client.request(request()).await.unwrap();
+    assert!(cached.from_cache);
+    assert_eq!(cached.text().as_deref(), Some("client"));
+    assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+}
+
+/// Test that incomplete responses are not cached
+#[tokio::test]
+async fn test_response_cache_skips_incomplete_responses() {
+    let incomplete = json!({
+        "id": "resp_cut",
+        "object": "response",
+        "status": "incomplete",
+        "incomplete_details": {"reason": "max_output_tokens"},
+        "output": []
+    });
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply {
+            response: Some(incomplete),
+            ..Default::default()
+        },
+        MockReply::text("complete"),
+        MockReply::text("unused"),
+    ]))
+    .await
+    .unwrap();
+    let cache = ResponseCache::in_memory(16);
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_cache(cache.clone());
+    let request = || {
+        Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Long answer")
+            .max_output_tokens(16)
+            .build()
+    };
+
+    let cut = client.request(request()).await.unwrap();
+    assert_eq!(cut.status, Some(Status::Unknown("incomplete".to_string())));
+
+    let retried = client.request(request()).await.unwrap();
+    assert!(!retried.from_cache);
+    assert_eq!(retried.text().as_deref(), Some("complete"));
+
+    let cached = client.request(request()).await.unwrap();
+    assert!(cached.from_cache);
+    assert_eq!(server.requests().len(), 2);
+}
+
+/// Test that background requests bypass the cache
+#[tokio::test]
+async fn test_response_cache_skips_background_requests() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("first"),
+        MockReply::text("second"),
+    ]))
+    .await
+    .unwrap();
+    let cache = ResponseCache::in_memory(16);
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_cache(cache.clone());
+    let request = || {
+        Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Run later")
+            .store(true)
+            .background(true)
+            .build()
+    };
+
+    client.request(request()).await.unwrap();
+    let second = client.request(request()).await.unwrap();
+    assert!(!second.from_cache);
+    assert_eq!(second.text().as_deref(), Some("second"));
+    assert_eq!(cache.stats(), CacheStats::default());
+}
+
+/// Test that one cache shared by clients for different accounts keeps their responses apart
+#[tokio::test]
+async fn test_response_cache_is_scoped_to_the_client() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("org a"),
+        MockReply::text("org b"),
+        MockReply::text("other endpoint"),
+    ]))
+    .await
+    .unwrap();
+    let other = MockServer::start(MockScript::default()).await.unwrap();
+    let cache = ResponseCache::in_memory(16);
+    let client = |base_url: String, organization: &str| {
+        Gpt5Client::new("test-api-key".to_string())
+            .with_base_url(base_url)
+            .with_organization(organization.to_string())
+            .with_cache(cache.clone())
+    };
+    let request = || {
+        Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Shared prompt")
+            .build()
+    };
+
+    let org_a = client(server.base_url(), "org-a");
+    let org_b = client(server.base_url(), "org-b");
+    let elsewhere = client(other.base_url(), "org-a");
+    assert_eq!(
+        org_a.request(request()).await.unwrap().text().as_deref(),
+        Some("org a")
+    );
+    let b = org_b.request(request()).await.unwrap();
+    assert!(!b.from_cache);
+    assert_eq!(b.text().as_deref(), Some("org b"));
+    assert!(!elsewhere.request(request()).await.unwrap().from_cache);
+    assert_eq!(other.requests().len(), 1);
+
+    let cached = org_a.request(request()).await.unwrap();
+    assert!(cached.from_cache);
+    assert_eq!(cached.text().as_deref(), Some("org a"));
+    // Entries written through a client are not visible without it
+    assert!(cache.get(&request()).is_none());
+}
+
+/// Test the on-disk cache backend and TTL expiry
+#[tokio::test]
+async fn test_response_cache_on_disk_with_ttl() {
+    let server = MockServer::start(MockScript::new(vec![MockReply::text("from disk")]))
+        .await
+        .unwrap();
+    let dir = std::env::temp_dir().join(format!("gpt5-cache-test-{}", std::process::id()));
+    let request = || {
+        Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Disk prompt")
+            .build()
+    };
+
+    let entries = || std::fs::read_dir(&dir).map_or(0, |files| files.count());
+    let client = |cache: ResponseCache| {
+        Gpt5Client::new("test-api-key".to_string())
+            .with_base_url(server.base_url())
+            .with_cache(cache)
+    };
+    let fresh = client(ResponseCache::on_disk(&dir))
+        .request(request())
+        .await
+        .unwrap();
+    assert_eq!(entries(), 1);
+
+    // A new cache over the same directory sees the stored entry
+    let reopened = ResponseCache::on_disk(&dir);
+    let cached = client(reopened.clone()).request(request()).await.unwrap();
+    assert!(cached.from_cache);
+    assert_eq!(cached.text().as_deref(), Some("from disk"));
+    assert_eq!(server.requests().len(), 1);
+
+    let expiring = ResponseCache::on_disk(&dir).with_ttl(Duration::from_millis(20));
+    expiring.put(&request(), &fresh);
+    assert!(expiring.get(&request()).is_some());
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    assert!(expiring.get(&request()).is_none());
+
+    reopened.clear();
+    assert_eq!(entries(), 0);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Span id, span name and recorded fields
+type CapturedSpan = (u64, String, HashMap<String, String>);
+
+/// Tracing layer that captures span fields and event messages
+#[derive(Clone, Default)]
+struct CapturingLayer {
+    spans: Arc<Mutex<Vec<CapturedSpan>>>,
+    events: Arc<Mutex<Vec<(tracing::Level, String)>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl tracing::field::Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            format!("{:?}", value).trim_matches('"').to_string(),
+        );
+    }
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CapturingLayer {
+    fn on_new_span(
+        &self,
+        attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let mut fields = HashMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        self.spans.lock().unwrap().push((
+            id.into_u64(),
+            attrs.metadata().name().to_string(),
+            fields,
+        ));
+    }
+
+    fn on_record(
+        &self,
+        id: &tracing::span::Id,
+        values: &tracing::span::Record<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let mut spans = self.spans.lock().unwrap();
+        // Span ids are recycled once closed, so update the newest match
+        if let Some((_, _, fields)) = spans
+            .iter_mut()
+            .rev()
+            .find(|(span, _, _)| *span == id.into_u64())
+        {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let mut fields = HashMap::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        let message = fields.remove("message").unwrap_or_default();
+        self.events
+            .lock()
+            .unwrap()
+            .push((*event.metadata().level(), message));
+    }
+}
+
+/// Test that each request produces a GenAI span and bodies stay at TRACE level
+#[tokio::test]
+async fn test_request_span_records_genai_attributes() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("secret answer text"),
+        MockReply::error(429),
+    ]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let layer = CapturingLayer::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::layer::SubscriberExt::with(
+        tracing_subscriber::registry(),
+        layer.clone(),
+    ));
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+        .input("one two three")
+        .reasoning_effort(ReasoningEffort::High)
+        .max_output_tokens(200)
+        .build();
+    client.request(request).await.unwrap();
+    client
+        .simple(Gpt5Model::Gpt5Nano, "again")
+        .await
+        .unwrap_err();
+
+    let spans: Vec<_> = layer
+        .spans
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, name, _)| name == "gen_ai.request")
+        .map(|(_, _, fields)| fields.clone())
+        .collect();
+    assert_eq!(spans.len(), 2);
+
+    let ok = &spans[0];
+    assert_eq!(ok["gen_ai.system"], "openai");
+    assert_eq!(ok["gen_ai.request.model"], "gpt-5-mini");
+    assert_eq!(ok["gen_ai.request.reasoning_effort"], "high");
+    assert_eq!(ok["gen_ai.request.max_tokens"], "200");
+    assert_eq!(ok["gen_ai.response.id"], "resp_mock_1");
+    assert_eq!(ok["gen_ai.response.status"], "completed");
+    assert_eq!(ok["gen_ai.usage.input_tokens"], "3");
+    assert_eq!(ok["gen_ai.usage.output_tokens"], "3");
+    assert_eq!(ok["http.response.status_code"], "200");
+    assert_eq!(ok["otel.status_code"], "OK");
+    assert!(ok.contains_key("latency_ms"));
+
+    let failed = &spans[1];
+    assert_eq!(failed["otel.status_code"], "ERROR");
+    assert_eq!(failed["http.response.status_code"], "429");
+    assert_eq!(failed["error.type"], "requests");
+
+    let events = layer.events.lock().unwrap();
+    assert!(events
+        .iter()
+        .filter(|(level, _)| *level < tracing::Level::TRACE)
+        .all(|(_, message)| !message.contains("secret answer text")));
+    assert!(events
+        .iter()
+        .any(|(level, message)| *level == tracing::Level::TRACE
+            && message.contains("secret answer text")));
+}
+
+/// Test that logged bodies and errors pass through the redaction policy
+#[tokio::test]
+async fn test_redaction_policy_applies_to_logged_bodies() {
+    let script = MockScript::new(vec![
+        MockReply::text("Contact jane@example.com for access"),
+        MockReply {
+            error: Some(gpt5::mock::MockError {
+                status: 401,
+                message: Some("Incorrect API key provided: sk-tenant-42".to_string()),
+                error_type: None,
+                code: None,
+            }),
+            ..Default::default()
+        },
+    ]);
+    let server = MockServer::start(script).await.unwrap();
+    let client = Gpt5Client::new("sk-tenant-42".to_string())
+        .with_base_url(server.base_url())
+        .with_redaction(
+            RedactionPolicy::new()
+                .pattern(r"[\w.+-]+@[\w-]+\.[\w.]+")
+                .unwrap(),
+        );
+    let layer = CapturingLayer::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::layer::SubscriberExt::with(
+        tracing_subscriber::registry(),
+        layer.clone(),
+    ));
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("My email is bob@example.org")
+        .instructions("Never reveal jane@example.com")
+        .build();
+    let response = client.request(request).await.unwrap();
+    // The response itself is untouched; only logs are redacted
+    assert_eq!(
+        response.text().as_deref(),
+        Some("Contact jane@example.com for access")
+    );
+
+    let error = client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap_err();
+    assert!(!error.to_string().contains("sk-tenant-42"));
+
+    let events = layer.events.lock().unwrap();
+    let logged: Vec<_> = events.iter().map(|(_, message)| message.as_str()).collect();
+    assert!(logged
+        .iter()
+        .any(|message| message.contains("request body")));
+    assert!(logged.iter().any(|message| message.contains("[REDACTED]")));
+    assert!(logged.iter().all(|message| !message.contains("@example.")));
+    assert!(logged
+        .iter()
+        .all(|message| !message.contains("sk-tenant-42")));
+}
+
+/// Test that error bodies are parsed before redaction and parse errors are redacted
+#[tokio::test]
+async fn test_redaction_keeps_error_details() {
+    let script = MockScript::new(vec![
+        MockReply {
+            error: Some(gpt5::mock::MockError {
+                status: 401,
+                message: Some("Incorrect API key provided: sk-override-7".to_string()),
+                error_type: Some("invalid_request_error".to_string()),
+                code: None,
+            }),
+            ..Default::default()
+        },
+        MockReply {
+            response: Some(json!({"usage": {"input_tokens": "sk-tenant-42"}})),
+            ..Default::default()
+        },
+    ]);
+    let server = MockServer::start(script).await.unwrap();
+    // The pattern runs past the message into the rest of the JSON body
+    let client = Gpt5Client::new("sk-tenant-42".to_string())
+        .with_base_url(server.base_url())
+        .with_redaction(RedactionPolicy::new().pattern(r"provided: \S+").unwrap());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .build();
+    let options = RequestOptions::new().api_key("sk-override-7");
+    let error = client
+        .request_with_options(request, options)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        error,
+        "OpenAI API error (status 401 Unauthorized): Incorrect API key [REDACTED]"
+    );
+
+    let error = client
+        .simple(Gpt5Model::Gpt5Nano, "Hi")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.starts_with("Failed to parse GPT-5 response"));
+    assert!(!error.contains("sk-tenant-42"));
+}
+
+/// Test that organization and project headers are attached to requests
+#[tokio::test]
+async fn test_organization_and_project_headers() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_organization("org-123".to_string())
+        .with_project("proj_abc".to_string());
+
+    client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("openai-organization"), Some("org-123"));
+    assert_eq!(requests[0].header("openai-project"), Some("proj_abc"));
+}
+
+/// Test typed request parameters and their echo on the response
+#[tokio::test]
+async fn test_typed_request_parameters() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .store(true)
+        .metadata("tenant", "acme")
+        .parallel_tool_calls(false)
+        .max_tool_calls(3)
+        .truncation(Truncation::Auto)
+        .service_tier(ServiceTier::Auto)
+        .safety_identifier("user-hash-123")
+        .prompt_cache_key("support-bot")
+        .background(false)
+        .build_checked()
+        .unwrap();
+    let response = client.request(request).await.unwrap();
+
+    let body = &server.requests()[0].body;
+    assert_eq!(body["store"], json!(true));
+    assert_eq!(body["metadata"], json!({ "tenant": "acme" }));
+    assert_eq!(body["parallel_tool_calls"], json!(false));
+    assert_eq!(body["max_tool_calls"], json!(3));
+    assert_eq!(body["truncation"], json!("auto"));
+    assert_eq!(body["service_tier"], json!("auto"));
+    assert_eq!(body["safety_identifier"], json!("user-hash-123"));
+    assert_eq!(body["prompt_cache_key"], json!("support-bot"));
+    assert_eq!(body["background"], json!(false));
+    assert!(body.get("user").is_none());
+
+    assert_eq!(response.store, Some(true));
+    assert_eq!(response.metadata.unwrap()["tenant"], json!("acme"));
+    assert_eq!(response.parallel_tool_calls, Some(false));
+    assert_eq!(response.max_tool_calls, Some(3));
+    assert_eq!(response.truncation, Some(Truncation::Auto));
+    assert_eq!(response.service_tier, Some(ServiceTier::Default));
+    assert_eq!(response.safety_identifier.as_deref(), Some("user-hash-123"));
+    assert_eq!(response.prompt_cache_key.as_deref(), Some("support-bot"));
+    assert_eq!(response.background, Some(false));
+
+    // Invalid combinations are caught before sending
+    let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .background(true)
+        .store(false)
+        .metadata(&"k".repeat(65), "v")
+        .build_checked()
+        .unwrap_err();
+    let fields: Vec<&str> = errors
+        .issues()
+        .iter()
+        .map(|issue| issue.field.as_str())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            format!("metadata.{}", "k".repeat(65)).as_str(),
+            "background"
+        ]
+    );
+}
+
+/// Test that web search options reach the API and client-only options do not
+#[tokio::test]
+async fn test_web_search_tool_options() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+        .input("Latest Rust release?")
+        .web_search_context_size(SearchContextSize::High)
+        .web_search_user_location(UserLocation {
+            country: Some("US".to_string()),
+            city: Some("Seattle".to_string()),
+            region: Some("Washington".to_string()),
+            timezone: Some("America/Los_Angeles".to_string()),
+        })
+        .web_search_allowed_domains(["blog.rust-lang.org", "github.com"])
+        .web_search_query("rust release")
+        .web_search_max_results(3)
+        .build_checked()
+        .unwrap();
+    assert_eq!(
+        request.web_search_config.as_ref().unwrap().query.as_deref(),
+        Some("rust release")
+    );
+    client.request(request).await.unwrap();
+
+    assert_eq!(
+        server.requests()[0].body["tools"],
+        json!([{
+            "type": "web_search",
+            "search_context_size": "high",
+            "user_location": {
+                "type": "approximate",
+                "country": "US",
+                "city": "Seattle",
+                "region": "Washington",
+                "timezone": "America/Los_Angeles"
+            },
+            "filters": { "allowed_domains": ["blog.rust-lang.org", "github.com"] }
+        }])
+    );
+
+    // Tool-specific options round-trip through `Tool::options`
+    let tool: Tool = serde_json::from_value(json!({
+        "type": "function",
+        "name": "lookup",
+        "strict": true
+    }))
+    .unwrap();
+    assert_eq!(tool.options["strict"], json!(true));
+
+    let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+        .input("Hi")
+        .web_search_allowed_domains(["https://example.com"])
+        .web_search_user_location(UserLocation {
+            country: Some("USA".to_string()),
+            ..Default::default()
+        })
+        .build_checked()
+        .unwrap_err();
+    let fields: Vec<&str> = errors
+        .issues()
+        .iter()
+        .map(|issue| issue.field.as_str())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "web_search.filters.allowed_domains[0]",
+            "web_search.user_location.country"
+        ]
+    );
+}
+
+/// Test typed web_search_call items, annotations and citations
+#[tokio::test]
+async fn test_web_search_calls_and_citations() {
+    let text = "Rust 1.90 shipped in September. It uses LLD by default on Linux.";
+    let body = json!({
+        "id": "resp_search",
+        "object": "response",
+        "status": "completed",
+        "model": "gpt-5-mini",
+        "output": [
+            {
+                "type": "web_search_call",
+                "id": "ws_1",
+                "status": "completed",
+                "action": {
+                    "type": "search",
+                    "query": "rust 1.90 release",
+                    "sources": [{"type": "url", "url": "https://blog.rust-lang.org/"}]
+                }
+            },
+            {
+                "type": "web_search_call",
+                "id": "ws_2",
+                "status": "completed",
+                "action": {"type": "open_page", "url": "https://blog.rust-lang.org/"}
+            },
+            {
+                "type": "web_search_call",
+                "id": "ws_3",
+                "status": "completed",
+                "action": {"type": "scroll", "pixels": 400}
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{
+                    "type": "output_text",
+                    "text": text,
+                    "annotations": [
+                        {
+                            "type": "url_citation",
+                            "start_index": 0,
+                            "end_index": 31,
+                            "url": "https://blog.rust-lang.org/",
+                            "title": "Rust Blog"
+                        },
+                        {
+                            "type": "url_citation",
+                            "start_index": 32,
+                            "end_index": 64,
+                            "url": "https://github.com/rust-lang/rust"
+                        },
+                        {"type": "file_citation", "file_id": "file_1", "filename": "notes.md", "index": 64},
+                        {"type": "container_file_citation", "container_id": "cntr_1"}
+                    ]
+                }]
+            }
+        ]
+    });
+    let server = MockServer::start(MockScript::new(vec![MockReply {
+        response: Some(body),
+        ..Default::default()
+    }]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+        .input("What is new in Rust?")
+        .web_search_sources(true)
+        .build();
+    let response = client.request(request).await.unwrap();
+    assert_eq!(
+        server.requests()[0].body["include"],
+        json!(["web_search_call.action.sources"])
+    );
+
+    let calls = response.web_search_calls();
+    assert_eq!(calls.len(), 3);
+    let search = calls[0].action.as_ref().unwrap();
+    assert_eq!(search.query(), Some("rust 1.90 release"));
+    assert_eq!(
+        search.sources()[0].url.as_deref(),
+        Some("https://blog.rust-lang.org/")
+    );
+    assert!(matches!(
+        calls[1].action,
+        Some(WebSearchAction::OpenPage { .. })
+    ));
+    assert!(matches!(calls[2].action, Some(WebSearchAction::Unknown(_))));
+
+    let annotations = response.output.as_ref().unwrap()[3]
+        .content
+        .as_ref()
+        .unwrap()[0]
+        .annotations
+        .as_ref()
+        .unwrap();
+    assert!(matches!(
+        &annotations[2],
+        Annotation::FileCitation { file_id, .. } if file_id == "file_1"
+    ));
+    assert_eq!(
+        serde_json::to_value(&annotations[3]).unwrap(),
+        json!({"type": "container_file_citation", "container_id": "cntr_1"})
+    );
+
+    let citations = response.citations();
+    assert_eq!(citations.len(), 2);
+    assert_eq!(citations[0].title.as_deref(), Some("Rust Blog"));
+    assert_eq!(
+        citations[0].cited_text.as_deref(),
+        Some("Rust 1.90 shipped in September.")
+    );
+    assert_eq!(citations[1].url, "https://github.com/rust-lang/rust");
+    assert_eq!(citations[1].text_index, 0);
+    assert_eq!(
+        citations[1].cited_text.as_deref(),
+        Some("It uses LLD by default on Linux.")
+    );
+}
+
+/// Test the file_search tool definition, file_search_call items and file citations
+#[tokio::test]
+async fn test_file_search_tool_and_calls() {
+    let body = json!({
+        "id": "resp_files",
+        "object": "response",
+        "status": "completed",
+        "model": "gpt-5-mini",
+        "output": [
+            {
+                "type": "file_search_call",
+                "id": "fs_1",
+                "status": "completed",
+                "queries": ["refund window", "refund policy"],
+                "results": [{
+                    "file_id": "file-policy",
+                    "filename": "policy.md",
+                    "score": 0.87,
+                    "text": "Refunds are accepted within 30 days.",
+                    "attributes": {"team": "support"}
+                }]
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{
+                    "type": "output_text",
+                    "text": "You can get a refund within 30 days.",
+                    "annotations": [
+                        {"type": "file_citation", "file_id": "file-policy", "filename": "policy.md", "index": 36}
+                    ]
+                }]
+            }
+        ]
+    });
+    let server = MockServer::start(MockScript::new(vec![MockReply {
+        response: Some(body),
+        ..Default::default()
+    }]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+        .input("What is the refund window?")
+        .file_search(["vs_policies", "vs_faq"])
+        .file_search_max_results(8)
+        .file_search_ranking(RankingOptions {
+            ranker: Some(Ranker::Auto),
+            score_threshold: Some(0.4),
+        })
+        .file_search_filter(AttributeFilter::or([
+            AttributeFilter::eq("team", "support"),
+            AttributeFilter::lt("year", 2020),
+        ]))
+        .file_search_results(true)
+        .build_checked()
+        .unwrap();
+    let response = client.request(request).await.unwrap();
+
+    let sent = &server.requests()[0].body;
+    assert_eq!(
+        sent["tools"],
+        json!([{
+            "type": "file_search",
+            "vector_store_ids": ["vs_policies", "vs_faq"],
+            "max_num_results": 8,
+            "ranking_options": {"ranker": "auto", "score_threshold": 0.4},
+            "filters": {
+                "type": "or",
+                "filters": [
+                    {"type": "eq", "key": "team", "value": "support"},
+                    {"type": "lt", "key": "year", "value": 2020}
+                ]
+            }
+        }])
+    );
+    assert_eq!(sent["include"], json!(["file_search_call.results"]));
+
+    let calls = response.file_search_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].output_type, OutputType::FileSearchCall);
+    assert_eq!(
+        calls[0].queries.as_deref().unwrap(),
+        ["refund window", "refund policy"]
+    );
+    let result = &calls[0].results.as_ref().unwrap()[0];
+    assert_eq!(result.filename.as_deref(), Some("policy.md"));
+    assert_eq!(result.score, Some(0.87));
+    assert_eq!(result.attributes.as_ref().unwrap()["team"], "support");
+
+    let citations = response.file_citations();
+    assert_eq!(citations.len(), 1);
+    assert_eq!(citations[0].file_id, "file-policy");
+    assert_eq!(citations[0].index, Some(36));
+    assert!(response.citations().is_empty());
+
+    let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Mini)
+        .input("Hi")
+        .file_search(Vec::<String>::new())
+        .file_search_max_results(80)
+        .file_search_ranking(RankingOptions {
+            score_threshold: Some(1.5),
+            ..Default::default()
+        })
+        .build_checked()
+        .unwrap_err();
+    let fields: Vec<&str> = errors
+        .issues()
+        .iter()
+        .map(|issue| issue.field.as_str())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "file_search.vector_store_ids",
+            "file_search.max_num_results",
+            "file_search.ranking_options.score_threshold"
+        ]
+    );
+}
+
+/// Test creating, filling, polling, listing and deleting vector stores
+#[tokio::test]
+async fn test_vector_store_lifecycle() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let stores = client.vector_stores();
+
+    let store = stores
+        .create(
+            CreateVectorStore::new("handbook")
+                .file_ids(["file-a"])
+                .expires_after_days(7)
+                .metadata("owner", "support"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(store.id, "vs_mock_1");
+    assert_eq!(store.status, VectorStoreStatus::InProgress);
+    assert!(!store.is_ready());
+    assert_eq!(store.file_counts.in_progress, 1);
+
+    let attributes = HashMap::from([("year".to_string(), json!(2025))]);
+    let file = stores
+        .attach_file_with_attributes(&store.id, "file-b", attributes)
+        .await
+        .unwrap();
+    assert_eq!(file.status, VectorStoreFileStatus::InProgress);
+    assert_eq!(file.vector_store_id.as_deref(), Some("vs_mock_1"));
+
+    let ready = stores
+        .wait_until_ready(&store.id, Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert!(ready.is_ready());
+    assert_eq!(ready.file_counts.completed, 2);
+    assert_eq!(ready.name.as_deref(), Some("handbook"));
+    assert_eq!(ready.expires_after.as_ref().unwrap().days, 7);
+
+    let files = stores.list_files(&store.id).await.unwrap();
+    let ids: Vec<&str> = files.iter().map(|file| file.id.as_str()).collect();
+    assert_eq!(ids, ["file-a", "file-b"]);
+    let file = stores.retrieve_file(&store.id, "file-b").await.unwrap();
+    assert_eq!(file.status, VectorStoreFileStatus::Completed);
+    assert_eq!(file.attributes.unwrap()["year"], 2025);
+
+    assert!(stores.detach_file(&store.id, "file-a").await.unwrap());
+    assert_eq!(stores.list().await.unwrap()[0].file_counts.total, 1);
+    assert!(stores.delete(&store.id).await.unwrap());
+    assert!(stores.list().await.unwrap().is_empty());
+    assert!(stores.retrieve(&store.id).await.is_err());
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/v1/vector_stores");
+    assert_eq!(
+        requests[0].body,
+        json!({
+            "name": "handbook",
+            "file_ids": ["file-a"],
+            "expires_after": {"anchor": "last_active_at", "days": 7},
+            "metadata": {"owner": "support"}
+        })
+    );
+    assert_eq!(requests[1].path, "/v1/vector_stores/vs_mock_1/files");
+    assert_eq!(
+        requests[1].body,
+        json!({"file_id": "file-b", "attributes": {"year": 2025}})
+    );
+    // The first poll still sees the files in progress
+    assert_eq!(requests[2].path, "/v1/vector_stores/vs_mock_1");
+    assert_eq!(requests[3].path, "/v1/vector_stores/vs_mock_1");
+    assert_eq!(
+        requests[4].path,
+        "/v1/vector_stores/vs_mock_1/files?limit=100"
+    );
+    assert_eq!(requests[6].method, "DELETE");
+    assert_eq!(
+        requests[6].header("authorization"),
+        Some("Bearer test-api-key")
+    );
+
+    // Polling gives up once the timeout has passed
+    let store = stores
+        .create(CreateVectorStore::new("slow").file_ids(["file-c"]))
+        .await
+        .unwrap();
+    let error = stores
+        .wait_until_ready(&store.id, Duration::ZERO)
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Vector store vs_mock_1 is still processing"));
+}
+
+/// Test that ids are percent-encoded into vector store paths
+#[tokio::test]
+async fn test_vector_store_ids_are_encoded() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let stores = client.vector_stores();
+
+    assert!(stores.retrieve("vs_1/../files?x=1").await.is_err());
+    assert!(stores.detach_file("vs 2", "file#3").await.is_err());
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v1/vector_stores/vs_1%2F..%2Ffiles%3Fx%3D1"
+    );
+    assert_eq!(requests[1].path, "/v1/vector_stores/vs%202/files/file%233");
+}
+
+/// Test the code_interpreter tool, code_interpreter_call items and container downloads
+#[tokio::test]
+async fn test_code_interpreter_calls_and_downloads() {
+    let body = json!({
+        "id": "resp_chart",
+        "object": "response",
+        "status": "completed",
+        "model": "gpt-5",
+        "output": [
+            {
+                "type": "code_interpreter_call",
+                "id": "ci_1",
+                "status": "completed",
+                "code": "df = pd.read_csv('sales.csv')\ndf.plot().figure.savefig('revenue.png')",
+                "container_id": "cntr_1",
+                "outputs": [
+                    {"type": "logs", "logs": "rows: 12\n"},
+                    {"type": "image", "url": "data:image/png;base64,iVBORw0KGgo="},
+                    {"type": "files", "files": [{"file_id": "cfile_2", "mime_type": "text/csv"}]},
+                    {"type": "video", "url": "https://example.com/v.mp4"}
+                ]
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{
+                    "type": "output_text",
+                    "text": "Here is the chart and a copy from last year.",
+                    "annotations": [
+                        {
+                            "type": "container_file_citation",
+                            "container_id": "cntr_1",
+                            "file_id": "cfile_1",
+                            "filename": "/mnt/data/revenue.png",
+                            "start_index": 12,
+                            "end_index": 17
+                        },
+                        {
+                            "type": "container_file_citation",
+                            "container_id": "cntr_1",
+                            "file_id": "cfile_3",
+                            "filename": "revenue.png"
+                        },
+                        {
+                            "type": "container_file_citation",
+                            "container_id": "cntr_1",
+                            "file_id": "cfile_1",
+                            "filename": "revenue.png"
+                        }
+                    ]
+                }]
+            }
+        ]
+    });
+    let server = MockServer::start(MockScript::new(vec![MockReply {
+        response: Some(body),
+        ..Default::default()
+    }]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("Chart revenue from sales.csv")
+        .code_interpreter_files(["file-sales"])
+        .code_interpreter()
+        .code_interpreter_outputs(true)
+        .build_checked()
+        .unwrap();
+    let response = client.request(request).await.unwrap();
+
+    let sent = &server.requests()[0].body;
+    assert_eq!(
+        sent["tools"],
+        json!([{"type": "code_interpreter", "container": {"type": "auto", "file_ids": ["file-sales"]}}])
+    );
+    assert_eq!(sent["include"], json!(["code_interpreter_call.outputs"]));
+
+    let calls = response.code_interpreter_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].container_id.as_deref(), Some("cntr_1"));
+    assert!(calls[0].code.as_deref().unwrap().starts_with("df = "));
+    let outputs = calls[0].outputs.as_ref().unwrap();
+    assert_eq!(
+        outputs[0],
+        CodeInterpreterOutput::Logs {
+            logs: "rows: 12\n".to_string()
+        }
+    );
+    assert!(
+        matches!(&outputs[1], CodeInterpreterOutput::Image { url } if url.starts_with("data:image/png"))
+    );
+    assert!(
+        matches!(&outputs[2], CodeInterpreterOutput::Files { files } if files[0].file_id == "cfile_2")
+    );
+    assert!(matches!(outputs[3], CodeInterpreterOutput::Unknown(_)));
+
+    let files = response.container_files();
+    let names: Vec<String> = files.iter().map(|file| file.local_name()).collect();
+    assert_eq!(names, ["cfile_2", "revenue.png", "revenue.png"]);
+    assert_eq!(
+        files[1],
+        ContainerFile {
+            container_id: "cntr_1".to_string(),
+            file_id: "cfile_1".to_string(),
+            filename: Some("/mnt/data/revenue.png".to_string()),
+        }
+    );
+
+    let dir = std::env::temp_dir().join(format!("gpt5-containers-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let paths = client
+        .download_container_files(&response, &dir)
+        .await
+        .unwrap();
+    assert_eq!(
+        paths,
+        [
+            dir.join("cfile_2"),
+            dir.join("revenue.png"),
+            dir.join("cfile_3-revenue.png")
+        ]
+    );
+    assert_eq!(
+        std::fs::read_to_string(&paths[2]).unwrap(),
+        "mock contents of cfile_3"
+    );
+    assert_eq!(
+        server.requests()[1].path,
+        "/v1/containers/cntr_1/files/cfile_2/content"
+    );
+    assert_eq!(
+        server.requests()[1].header("authorization"),
+        Some("Bearer test-api-key")
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // An explicit container is sent as a bare id and is not replaced by `code_interpreter`
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("Continue the analysis")
+        .code_interpreter_container("cntr_1")
+        .code_interpreter()
+        .build();
+    assert_eq!(request.tools.unwrap()[0].options["container"], "cntr_1");
+
+    let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("Hi")
+        .code_interpreter_container(" ")
+        .build_checked()
+        .unwrap_err();
+    assert_eq!(
+        errors.issues()[0].to_string(),
+        "code_interpreter.container: must not be empty"
+    );
+}
+
+/// Test that downloaded container files stay inside the directory and never overwrite each other
+#[tokio::test]
+async fn test_download_container_files_unique_names() {
+    let citation = |container: &str, file_id: &str, filename: Option<&str>| {
+        json!({
+            "type": "container_file_citation",
+            "container_id": container,
+            "file_id": file_id,
+            "filename": filename
+        })
+    };
+    let body = json!({
+        "output": [{
+            "type": "message",
+            "content": [{
+                "type": "output_text",
+                "text": "Charts attached.",
+                "annotations": [
+                    citation("cntr_1", "cfile_1", Some("chart.png")),
+                    citation("cntr_1", "cfile_2", Some("cfile_2-chart.png")),
+                    citation("cntr_2", "../cfile_2", Some("chart.png")),
+                    citation("cntr_3", "cfile_2", Some("chart.png")),
+                    citation("cntr_1", "/", None),
+                ]
+            }]
+        }]
+    });
+    let server = MockServer::start(MockScript::new(vec![MockReply {
+        response: Some(body),
+        ..Default::default()
+    }]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5)
+        .input("Chart it")
+        .build();
+    let response = client.request(request).await.unwrap();
+
+    let dir = std::env::temp_dir().join(format!("gpt5-unique-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let paths = client
+        .download_container_files(&response, &dir)
+        .await
+        .unwrap();
+    assert_eq!(
+        paths,
+        [
+            dir.join("chart.png"),
+            dir.join("cfile_2-chart.png"),
+            dir.join("cfile_2-2-chart.png"),
+            dir.join("cfile_2-3-chart.png"),
+            dir.join("file"),
+        ]
+    );
+    assert_eq!(
+        std::fs::read_to_string(&paths[2]).unwrap(),
+        "mock contents of ..%2Fcfile_2"
+    );
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 5);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that typed tool choices serialize and round-trip through the response
+#[tokio::test]
+async fn test_tool_choice_round_trip() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let weather_tool = Tool {
+        tool_type: "function".to_string(),
+        name: Some("get_weather".to_string()),
+        description: None,
+        parameters: Some(json!({"type": "object", "properties": {}})),
+        ..Default::default()
+    };
+
+    let choices = [
+        (ToolChoice::Required, json!("required")),
+        (
+            ToolChoice::Function("get_weather".to_string()),
+            json!({"type": "function", "name": "get_weather"}),
+        ),
+        (
+            ToolChoice::AllowedTools {
+                mode: AllowedToolsMode::Required,
+                tools: vec![
+                    ToolChoice::Function("get_weather".to_string()),
+                    ToolChoice::Hosted("web_search".to_string()),
+                ],
+            },
+            json!({
+                "type": "allowed_tools",
+                "mode": "required",
+                "tools": [{"type": "function", "name": "get_weather"}, {"type": "web_search"}]
+            }),
+        ),
+        (
+            ToolChoice::Hosted("web_search".to_string()),
+            json!({"type": "web_search"}),
+        ),
+    ];
+
+    for (index, (choice, expected)) in choices.into_iter().enumerate() {
+        let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+            .input("Weather in Boston?")
+            .tools(vec![weather_tool.clone()])
+            .web_search_enabled(true)
+            .tool_choice(choice.clone())
+            .build_checked()
+            .unwrap();
+        let response = client.request(request).await.unwrap();
+
+        assert_eq!(server.requests()[index].body["tool_choice"], expected);
+        assert_eq!(response.tool_choice, Some(choice));
+    }
+
+    // Unrecognized objects are preserved rather than failing to parse
+    let mcp = json!({"type": "mcp", "server_label": "deepwiki"});
+    let choice: ToolChoice = serde_json::from_value(mcp.clone()).unwrap();
+    assert_eq!(choice, ToolChoice::Unknown(mcp.clone()));
+    assert_eq!(serde_json::to_value(&choice).unwrap(), mcp);
+
+    // Forcing a tool the request does not offer is rejected
+    let errors = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .tools(vec![weather_tool])
+        .tool_choice(ToolChoice::Function("get_time".to_string()))
+        .build_checked()
+        .unwrap_err();
+    assert_eq!(errors.issues()[0].field, "tool_choice");
+}
+
+/// Test that the model policy gates requests before they are sent
+#[tokio::test]
+async fn test_model_policy() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let custom = |name: &str| Gpt5Model::Custom(name.to_string());
+
+    let strict = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let error = strict.simple(custom("acme-large"), "Hi").await.unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Only GPT-5 models are supported"));
+    strict
+        .simple(custom("gpt-5-2025-08-07"), "Hi")
+        .await
+        .unwrap();
+
+    let allowlist = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_model_policy(ModelPolicy::allowlist(["acme-large", "ft:gpt-5-mini:*"]));
+    allowlist.simple(custom("acme-large"), "Hi").await.unwrap();
+    allowlist
+        .simple(custom("ft:gpt-5-mini:acme::abc123"), "Hi")
+        .await
+        .unwrap();
+    let error = allowlist.simple(Gpt5Model::Gpt5, "Hi").await.unwrap_err();
+    assert!(error.to_string().contains("not in the client's allowlist"));
+
+    let predicate = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_model_policy(ModelPolicy::predicate(|model| model.ends_with("-internal")));
+    predicate
+        .simple(custom("gpt-5-internal"), "Hi")
+        .await
+        .unwrap();
+    assert!(predicate.simple(Gpt5Model::Gpt5Nano, "Hi").await.is_err());
+
+    let disabled = Gpt5Client::new("test-api-key".to_string())
+        .with_base_url(server.base_url())
+        .with_model_policy(ModelPolicy::Disabled);
+    disabled
+        .simple(custom("llama-3.1-70b"), "Hi")
+        .await
+        .unwrap();
+
+    // Rejected requests never reach the server
+    let models: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|request| request.body["model"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        models,
+        [
+            "gpt-5-2025-08-07",
+            "acme-large",
+            "ft:gpt-5-mini:acme::abc123",
+            "gpt-5-internal",
+            "llama-3.1-70b"
+        ]
+    );
+}
+
+/// Test listing, retrieving and checking models through `/v1/models`
+#[tokio::test]
+async fn test_list_and_retrieve_models() {
+    let script = MockScript::default().with_models(["gpt-5-mini", "ft:gpt-5-mini:acme::abc123"]);
+    let server = MockServer::start(script).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let models = client.list_models().await.unwrap();
+    let ids: Vec<&str> = models.iter().map(|model| model.id.as_str()).collect();
+    assert_eq!(ids, ["gpt-5-mini", "ft:gpt-5-mini:acme::abc123"]);
+    assert!(matches!(models[0].to_gpt5_model(), Gpt5Model::Gpt5Mini));
+    assert_eq!(models[0].info().unwrap().id, "gpt-5-mini");
+    let fine_tune: Gpt5Model = models[1].clone().into();
+    assert_eq!(fine_tune.as_str(), "ft:gpt-5-mini:acme::abc123");
+
+    let model: ModelObject = client.retrieve_model("gpt-5-mini").await.unwrap();
+    assert_eq!(model.owned_by.as_deref(), Some("system"));
+
+    client
+        .ensure_model_available(&Gpt5Model::Custom("ft:gpt-5-mini:acme::abc123".to_string()))
+        .await
+        .unwrap();
+    let error = client
+        .ensure_model_available(&Gpt5Model::Custom("gpt-5-mini-2031-01-01".to_string()))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Model gpt-5-mini-2031-01-01 is not available for this API key"));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/v1/models");
+    assert_eq!(requests[1].path, "/v1/models/gpt-5-mini");
+    assert_eq!(
+        requests[2].path,
+        "/v1/models/ft%3Agpt-5-mini%3Aacme%3A%3Aabc123"
+    );
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer test-api-key")
+    );
+
+    // The default model list mirrors the registry
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    client
+        .ensure_model_available(&Gpt5Model::Gpt5Nano)
+        .await
+        .unwrap();
+    assert_eq!(client.list_models().await.unwrap().len(), 6);
+}
+
+/// Test Azure OpenAI routing with `api-key` authentication
+#[tokio::test]
+async fn test_azure_api_key_routing() {
+    let server = MockServer::start(MockScript::new(vec![MockReply::text("from azure")]))
+        .await
+        .unwrap();
+    let client = Gpt5Client::azure(
+        server.base_url(),
+        AzureConfig::deployment("gpt5-prod", "2025-04-01-preview"),
+        "azure-key".to_string(),
+    );
+
+    let text = client.simple(Gpt5Model::Gpt5, "Hi").await.unwrap();
+    assert_eq!(text, "from azure");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/openai/deployments/gpt5-prod/responses?api-version=2025-04-01-preview"
+    );
+    assert_eq!(requests[0].header("api-key"), Some("azure-key"));
+    assert_eq!(requests[0].header("authorization"), None);
+}
+
+/// Test Azure OpenAI with an Entra ID token provider
+#[tokio::test]
+async fn test_azure_token_provider() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let calls = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&calls);
+    let client = Gpt5Client::azure_with_token_provider(
+        server.base_url(),
+        AzureConfig::resource("preview"),
+        move || {
+            let counter = Arc::clone(&counter);
+            async move {
+                *counter.lock().unwrap() += 1;
+                Ok("entra-token".to_string())
+            }
+        },
+    );
+
+    client.simple(Gpt5Model::Gpt5, "Hi").await.unwrap();
+    client.simple(Gpt5Model::Gpt5, "Again").await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/openai/responses?api-version=preview");
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer entra-token")
+    );
+    assert_eq!(requests[0].header("api-key"), None);
+    assert_eq!(*calls.lock().unwrap(), 2);
+}
+
+/// Test that Azure clients accept deployment names that are not `gpt-5*`
+#[tokio::test]
+async fn test_azure_custom_deployment_name() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::azure(
+        server.base_url(),
+        AzureConfig::resource("2025-04-01-preview"),
+        "azure-key".to_string(),
+    );
+
+    client
+        .simple(Gpt5Model::Custom("my-gpt5-prod".to_string()), "Hi")
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/openai/responses?api-version=2025-04-01-preview"
+    );
+    assert_eq!(requests[0].body["model"], "my-gpt5-prod");
+
+    let checked = client.with_model_policy(ModelPolicy::Gpt5Only);
+    assert!(checked
+        .simple(Gpt5Model::Custom("my-gpt5-prod".to_string()), "Hi")
+        .await
+        .is_err());
+}
+
+/// Test per-request headers, idempotency key and API key override
+#[tokio::test]
+async fn test_request_options_headers_and_api_key() {
+    let server = MockServer::start(MockScript::default()).await.unwrap();
+    let client = Gpt5Client::new("client-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .build();
+    let options = RequestOptions::new()
+        .header("x-tenant", "acme")
+        .unwrap()
+        .idempotency_key("job-7")
+        .api_key("tenant-key");
+    client.request_with_options(request, options).await.unwrap();
+    client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("x-tenant"), Some("acme"));
+    assert_eq!(requests[0].header("idempotency-key"), Some("job-7"));
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer tenant-key")
+    );
+    assert_eq!(requests[1].header("x-tenant"), None);
+    assert_eq!(
+        requests[1].header("authorization"),
+        Some("Bearer client-key")
+    );
+}
+
+/// Test that a per-request timeout overrides the client default
+#[tokio::test]
+async fn test_request_options_timeout() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("slow").with_latency(Duration::from_millis(500))
+    ]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Hi")
+        .build();
+    let options = RequestOptions::new().timeout(Duration::from_millis(50));
+    let error = client
+        .request_with_options(request, options)
+        .await
+        .unwrap_err();
+
+    let error = error.downcast_ref::<reqwest::Error>().unwrap();
+    assert!(error.is_timeout());
+}
+
+/// Test streaming text deltas and the final response
+#[tokio::test]
+async fn test_request_stream_events() {
+    let server = MockServer::start(MockScript::new(vec![MockReply::text("one two three")]))
+        .await
+        .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Count")
+        .build();
+    let options = RequestOptions::new().idempotency_key("stream-1");
+    let mut stream = client
+        .request_stream_with_options(request, options)
+        .await
+        .unwrap();
+
+    let mut deltas = Vec::new();
+    let mut completed = None;
+    while let Some(event) = stream.next().await {
+        match event.unwrap() {
+            StreamEvent::OutputTextDelta { delta, .. } => deltas.push(delta),
+            StreamEvent::Completed(response) => completed = Some(response),
+            _ => {}
+        }
+    }
+
+    assert_eq!(deltas, vec!["one ", "two ", "three"]);
+    let completed = completed.expect("stream should complete");
+    assert_eq!(completed.text().as_deref(), Some("one two three"));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].body["stream"], json!(true));
+    assert_eq!(requests[0].header("idempotency-key"), Some("stream-1"));
+
+    // Errors are reported before any events are read
+    let server = MockServer::start(MockScript::new(vec![MockReply::error(429)]))
+        .await
+        .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Count")
+        .build();
+    let error = client.request_stream(request).await.err().unwrap();
+    assert!(error.to_string().contains("429"));
+}
+
+/// Test that streamed text deltas point at the message, not an earlier function call
+#[tokio::test]
+async fn test_request_stream_output_index() {
+    let reply = MockReply {
+        text: Some("Checking the weather".to_string()),
+        ..MockReply::function_call("get_weather", json!({"city": "Paris"}))
+    };
+    let server = MockServer::start(MockScript::new(vec![reply]))
+        .await
+        .unwrap();
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Weather in Paris?")
+        .build();
+    let mut stream = client.request_stream(request).await.unwrap();
+
+    let mut indexes = Vec::new();
+    let mut completed = None;
+    while let Some(event) = stream.next().await {
+        match event.unwrap() {
+            StreamEvent::OutputTextDelta { output_index, .. } => indexes.push(output_index),
+            StreamEvent::Completed(response) => completed = Some(response),
+            _ => {}
+        }
+    }
+
+    assert_eq!(indexes, vec![Some(1), Some(1), Some(1)]);
+    let output = completed.unwrap().output.unwrap();
+    assert_eq!(output[1].output_type, OutputType::Message);
+}
+
+/// Test that the client builder applies its user agent and read timeout
+#[tokio::test]
+async fn test_client_builder_user_agent_and_read_timeout() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("fast"),
+        MockReply::text("slow").with_latency(Duration::from_millis(500)),
+    ]))
+    .await
+    .unwrap();
+    let client = Gpt5Client::builder("test-api-key")
+        .base_url(&server.base_url())
+        .user_agent("builder-test/1.0")
+        .read_timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    let text = client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap();
+    assert_eq!(text, "fast");
+    assert_eq!(
+        server.requests()[0].header("user-agent"),
+        Some("builder-test/1.0")
+    );
+
+    let error = client.simple(Gpt5Model::Gpt5Nano, "Hi").await.unwrap_err();
+    assert!(error.downcast_ref::<reqwest::Error>().unwrap().is_timeout());
+
+    // Clients built with `new` identify the crate
+    let client = Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url());
+    let _ = client.simple(Gpt5Model::Gpt5Nano, "Hi").await;
+    let user_agent = server.requests()[2]
+        .header("user-agent")
+        .map(str::to_string);
+    assert!(user_agent.unwrap().starts_with("gpt5-rust/"));
+}
+
+/// Test the blocking client against the mock server
+#[test]
+fn test_blocking_client() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime
+        .block_on(MockServer::start(MockScript::new(vec![
+            MockReply::text("sync hello"),
+            MockReply::text("streamed sync reply"),
+        ])))
+        .unwrap();
+
+    let client = gpt5::blocking::Gpt5Client::from_async(
+        Gpt5Client::new("test-api-key".to_string()).with_base_url(server.base_url()),
+    )
+    .unwrap();
+
+    let text = client.simple(Gpt5Model::Gpt5Nano, "Hi").unwrap();
+    assert_eq!(text, "sync hello");
+
+    let request = Gpt5RequestBuilder::new(Gpt5Model::Gpt5Nano)
+        .input("Stream please")
+        .build();
+    let deltas: Vec<String> = client
+        .request_stream(request)
+        .unwrap()
+        .filter_map(|event| match event.unwrap() {
+            StreamEvent::OutputTextDelta { delta, .. } => Some(delta),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(deltas.concat(), "streamed sync reply");
+}
+
+/// Run the `gpt5` binary against a mock server
+async fn run_cli(server: &MockServer, args: &[&str], stdin: &str) -> std::process::Output {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_gpt5"))
+        .args(args)
+        .env("OPENAI_API_KEY", "test-api-key")
+        .env("OPENAI_BASE_URL", server.base_url())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    tokio::task::spawn_blocking(move || child.wait_with_output().unwrap())
+        .await
+        .unwrap()
+}
+
+/// Test the one-shot CLI: flags, stdin prompts, streaming, JSON and usage output
+#[tokio::test]
+async fn test_cli_one_shot() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("Paris"),
+        MockReply::text("streamed words here"),
+        MockReply::text("{\"ok\": true}"),
+    ]))
+    .await
+    .unwrap();
+
+    let output = run_cli(
+        &server,
+        &[
+            "-m",
+            "gpt-5-mini",
+            "-i",
+            "Be brief",
+            "--effort",
+            "low",
+            "--verbosity",
+            "low",
+            "--max-tokens",
+            "50",
+            "--web-search",
+            "--usage",
+        ],
+        "What is the capital of France?",
+    )
+    .await;
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Paris\n");
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("usage: "));
+
+    let body = &server.requests()[0].body;
+    assert_eq!(body["model"], "gpt-5-mini");
+    assert_eq!(body["input"], "What is the capital of France?");
+    assert_eq!(body["instructions"], "Be brief");
+    assert_eq!(body["reasoning"]["effort"], "low");
+    assert_eq!(body["text"]["verbosity"], "low");
+    assert_eq!(body["max_output_tokens"], 50);
+    assert_eq!(body["tools"][0]["type"], "web_search");
+
+    let output = run_cli(&server, &["--stream", "Stream it"], "").await;
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "streamed words here\n"
+    );
+
+    let output = run_cli(&server, &["--json", "--json-output", "Give JSON"], "").await;
+    assert!(output.status.success(), "{:?}", output);
+    let response: Gpt5Response = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(response.text().as_deref(), Some("{\"ok\": true}"));
+    assert_eq!(
+        server.requests()[2].body["text"]["format"]["type"],
+        "json_object"
+    );
+
+    let output = run_cli(&server, &["--effort", "low"], "   ").await;
+    assert!(!output.status.success());
+}
+
+/// Test the interactive chat: context chaining, slash commands and transcripts
+#[tokio::test]
+async fn test_cli_chat_session() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("Hi there"),
+        MockReply::text("Still here"),
+        MockReply::text("Fresh start"),
+        MockReply::text("Welcome back"),
+    ]))
+    .await
+    .unwrap();
+    let transcript = std::env::temp_dir().join(format!("gpt5-chat-{}.json", std::process::id()));
+    let transcript_path = transcript.to_str().unwrap();
+
+    let script = format!(
+        "Hello\n/effort high\n/bogus\nHow are you?\n/usage\n/save {}\n/reset\nNew topic\n/quit\nignored\n",
+        transcript_path
+    );
+    let output = run_cli(&server, &["chat", "--plain", "-m", "gpt-5-mini"], &script).await;
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Hi there\nStill here\nFresh start\n"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown command /bogus"));
+    assert!(stderr.contains("usage: 2 calls"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].body.get("previous_response_id").is_none());
+    assert_eq!(requests[1].body["previous_response_id"], "resp_mock_1");
+    assert_eq!(requests[1].body["reasoning"]["effort"], "high");
+    assert_eq!(requests[1].body["stream"], true);
+    assert!(requests[2].body.get("previous_response_id").is_none());
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&transcript).unwrap()).unwrap();
+    assert_eq!(saved["model"], "gpt-5-mini");
+    assert_eq!(saved["effort"], "high");
+    assert_eq!(saved["previous_response_id"], "resp_mock_2");
+    assert_eq!(saved["messages"].as_array().unwrap().len(), 4);
+    assert_eq!(saved["messages"][3]["content"], "Still here");
+
+    let output = run_cli(
+        &server,
+        &["chat", "--plain", "--no-stream", "--load", transcript_path],
+        "Back again\n",
+    )
+    .await;
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Welcome back\n");
+
+    let requests = server.requests();
+    assert_eq!(requests[3].body["model"], "gpt-5-mini");
+    assert_eq!(requests[3].body["previous_response_id"], "resp_mock_2");
+    assert!(requests[3].body.get("stream").is_none());
+
+    let _ = std::fs::remove_file(&transcript);
+}
+
+/// Test `gpt5 batch`: mixed input lines, failures and resuming
+#[tokio::test]
+async fn test_cli_batch_resume() {
+    let server = MockServer::start(MockScript::new(vec![
+        MockReply::text("one"),
+        MockReply::text("two"),
+        MockReply::error(500),
+        MockReply::text("four"),
+    ]))
+    .await
+    .unwrap();
+
+    let dir = std::env::temp_dir().join(format!("gpt5-batch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("input.jsonl");
+    let output = dir.join("output.jsonl");
+    let _ = std::fs::remove_file(&output);
+    std::fs::write(
+        &input,
+        concat!(
+            "\"plain prompt\"\n",
+            "{\"id\": \"a\", \"prompt\": \"with flags\"}\n",
+            "\n",
+            "{\"id\": \"b\", \"model\": \"gpt-5-nano\", \"input\": \"full request\"}\n",
+            "{\"id\": \"c\", \"prompt\": \"last\"}\n",
+        ),
+    )
+    .unwrap();
+    let args = [
+        "batch",
+        input.to_str().unwrap(),
+        "-o",
+        output.to_str().unwrap(),
+        "--concurrency",
+        "1",
+        "--rpm",
+        "6000",
+        "-m",
+        "gpt-5-mini",
+    ];
+
+    let result = run_cli(&server, &args, "").await;
+    assert!(!result.status.success());
+    assert!(String::from_utf8_lossy(&result.stderr).contains("1 requests failed"));
+
+    let records: Vec<serde_json::Value> = std::fs::read_to_string(&output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0]["id"], "line-1");
+    assert_eq!(records[0]["text"], "one");
+    assert_eq!(records[1]["id"], "a");
+    assert_eq!(records[1]["response"]["id"], "resp_mock_2");
+    assert_eq!(records[2]["id"], "b");
+    assert!(records[2]["error"].as_str().unwrap().contains("500"));
+    assert_eq!(records[3]["text"], "four");
+
+    let requests = server.requests();
+    assert_eq!(requests[1].body["model"], "gpt-5-mini");
+    assert_eq!(requests[1].body["input"], "with flags");
+    assert_eq!(requests[2].body["model"], "gpt-5-nano");
+    assert!(requests[2].body.get("id").is_none());
+
+    // Only the failed id is retried
+    let result = run_cli(&server, &args, "").await;
+    assert!(result.status.success(), "{:?}", result);
+    assert_eq!(server.requests().len(), 5);
+    assert_eq!(server.requests()[4].body["input"], "full request");
+    let last = std::fs::read_to_string(&output)
+        .unwrap()
+        .lines()
+        .last()
+        .map(str::to_string)
+        .unwrap();
+    let last: serde_json::Value = serde_json::from_str(&last).unwrap();
+    assert_eq!(last["id"], "b");
+    assert_eq!(last["text"], "four");
+
+    let _ = std::fs::remove_dir_all(&dir);
 }